@@ -1,14 +1,127 @@
-#![no_std]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 extern crate alloc;
 
 use alloc::borrow::Cow;
-use alloc::string::ToString;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt;
 
-use serde::de::{Deserialize, Deserializer, Error as DeError};
-use serde::ser::Serializer;
+use serde::de::{
+    Deserialize, DeserializeSeed, Deserializer, Error as DeError, SeqAccess, Unexpected, Visitor,
+};
+use serde::ser::{Error as SerError, Serialize, Serializer};
 
-pub const fn date_to_iso8601(date: time::Date) -> iso8601::Date {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Iso8601SerdeError {
+    /// The input isn't an ISO 8601 representation of the named value, like `"date"`.
+    Parse(&'static str),
+    ComponentRange(::time::error::ComponentRange),
+    /// A component `time` has no range check for, like the `d` of an ISO week date.
+    OutOfRange {
+        name: &'static str,
+        minimum: i64,
+        maximum: i64,
+        value: i64,
+    },
+    /// An `iso8601::Duration` has years or months, whose length depends on the calendar.
+    NominalDuration,
+    /// A `time::Duration` is negative or too long to fit an `iso8601::Duration`.
+    DurationRange,
+    /// A `time::UtcOffset` has seconds, which an `iso8601::Time` has no field for.
+    SubMinuteOffset,
+    /// The input has both a `Z` and a numeric offset, like `00:00:00Z+02:00`.
+    ConflictingOffset,
+    /// A time of `24:00:00` without a date, which it would have ended.
+    EndOfDay,
+    /// A calendar date whose day is past the end of its month, like `2021-02-29`.
+    DayOfMonth {
+        year: i32,
+        month: u8,
+        day: u32,
+    },
+    /// An interval whose end comes before its start.
+    ReversedInterval,
+    /// A datetime without `Z` or a numeric offset, like `2020-01-01T12:00:00`, which only
+    /// [`datetime::assume_utc`] and [`datetime::lenient`] read as UTC.
+    MissingOffset,
+}
+
+impl fmt::Display for Iso8601SerdeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Iso8601SerdeError::Parse(expected) => write!(f, "invalid ISO 8601 {}", expected),
+            Iso8601SerdeError::ComponentRange(err) => err.fmt(f),
+            Iso8601SerdeError::OutOfRange {
+                name,
+                minimum,
+                maximum,
+                value,
+            } => write!(
+                f,
+                "{} must be in the range {}..={}, got {}",
+                name, minimum, maximum, value
+            ),
+            Iso8601SerdeError::NominalDuration => {
+                f.write_str("durations with years or months have no exact length")
+            }
+            Iso8601SerdeError::DurationRange => {
+                f.write_str("duration must be non-negative and at most u32::MAX days")
+            }
+            Iso8601SerdeError::SubMinuteOffset => {
+                f.write_str("UTC offset must be a whole number of minutes")
+            }
+            Iso8601SerdeError::ConflictingOffset => {
+                f.write_str("time has both a Z and a numeric UTC offset")
+            }
+            Iso8601SerdeError::EndOfDay => {
+                f.write_str("24:00:00 ends a day, so it needs a date to roll over into")
+            }
+            Iso8601SerdeError::DayOfMonth { year, month, day } => write!(
+                f,
+                "{} {} has only {} days, got day {}",
+                MONTH_NAMES[*month as usize - 1],
+                year,
+                days_in_month(*year, *month),
+                day
+            ),
+            Iso8601SerdeError::ReversedInterval => f.write_str("interval ends before it starts"),
+            Iso8601SerdeError::MissingOffset => f.write_str("datetime has no UTC offset"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Iso8601SerdeError {}
+
+impl From<::time::error::ComponentRange> for Iso8601SerdeError {
+    fn from(err: ::time::error::ComponentRange) -> Self {
+        Iso8601SerdeError::ComponentRange(err)
+    }
+}
+
+// `From::from` isn't callable from a `const fn`.
+const fn component_range(
+    result: Result<::time::Date, ::time::error::ComponentRange>,
+) -> Result<::time::Date, Iso8601SerdeError> {
+    match result {
+        Ok(value) => Ok(value),
+        Err(err) => Err(Iso8601SerdeError::ComponentRange(err)),
+    }
+}
+
+const fn time_component_range(
+    result: Result<::time::Time, ::time::error::ComponentRange>,
+) -> Result<::time::Time, Iso8601SerdeError> {
+    match result {
+        Ok(value) => Ok(value),
+        Err(err) => Err(Iso8601SerdeError::ComponentRange(err)),
+    }
+}
+
+pub const fn date_to_iso8601(date: ::time::Date) -> iso8601::Date {
     let (year, month, day) = date.as_ymd();
     iso8601::Date::YMD {
         year,
@@ -17,42 +130,388 @@ pub const fn date_to_iso8601(date: time::Date) -> iso8601::Date {
     }
 }
 
-const WEEKDAYS: [time::Weekday; 7] = [
-    time::Weekday::Monday,
-    time::Weekday::Tuesday,
-    time::Weekday::Wednesday,
-    time::Weekday::Thursday,
-    time::Weekday::Friday,
-    time::Weekday::Saturday,
-    time::Weekday::Sunday,
+/// Like [`date_to_iso8601`], but rejects years outside -9999..=9999, which `iso8601` can't read
+/// back: its parser takes exactly four digits after an optional sign.
+pub const fn try_date_to_iso8601(date: ::time::Date) -> Result<iso8601::Date, Iso8601SerdeError> {
+    let year = date.year();
+    if year < -9_999 || year > 9_999 {
+        return Err(Iso8601SerdeError::OutOfRange {
+            name: "year",
+            minimum: -9_999,
+            maximum: 9_999,
+            value: year as i64,
+        });
+    }
+    Ok(date_to_iso8601(date))
+}
+
+pub fn date_to_iso8601_week(date: ::time::Date) -> iso8601::Date {
+    let (year, week) = date.iso_year_week();
+    iso8601::Date::Week {
+        year,
+        ww: week as u32,
+        d: weekday_to_iso(date.weekday()) as u32,
+    }
+}
+
+pub const fn date_to_iso8601_ordinal(date: ::time::Date) -> iso8601::Date {
+    let (year, ordinal) = date.as_yo();
+    iso8601::Date::Ordinal {
+        year,
+        ddd: ordinal as u32,
+    }
+}
+
+/// Which of the three ISO 8601 date forms an [`iso8601::Date`] is written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateKind {
+    /// A calendar date, like `2020-01-01`.
+    Ymd,
+    /// A week date, like `2020-W01-3`.
+    Week,
+    /// An ordinal date, like `2020-001`.
+    Ordinal,
+}
+
+pub const fn iso8601_date_kind(date: &iso8601::Date) -> DateKind {
+    match date {
+        iso8601::Date::YMD { .. } => DateKind::Ymd,
+        iso8601::Date::Week { .. } => DateKind::Week,
+        iso8601::Date::Ordinal { .. } => DateKind::Ordinal,
+    }
+}
+
+/// Converts `date` to the given form, like [`date_to_iso8601`], [`date_to_iso8601_week`] or
+/// [`date_to_iso8601_ordinal`].
+pub fn date_to_iso8601_as(date: ::time::Date, kind: DateKind) -> iso8601::Date {
+    match kind {
+        DateKind::Ymd => date_to_iso8601(date),
+        DateKind::Week => date_to_iso8601_week(date),
+        DateKind::Ordinal => date_to_iso8601_ordinal(date),
+    }
+}
+
+const WEEKDAYS: [::time::Weekday; 7] = [
+    ::time::Weekday::Monday,
+    ::time::Weekday::Tuesday,
+    ::time::Weekday::Wednesday,
+    ::time::Weekday::Thursday,
+    ::time::Weekday::Friday,
+    ::time::Weekday::Saturday,
+    ::time::Weekday::Sunday,
+];
+
+/// The position of `weekday` in the ISO 8601 week, from Monday as 0 to Sunday as 6.
+pub const fn iso_index_of(weekday: ::time::Weekday) -> usize {
+    match weekday {
+        ::time::Weekday::Monday => 0,
+        ::time::Weekday::Tuesday => 1,
+        ::time::Weekday::Wednesday => 2,
+        ::time::Weekday::Thursday => 3,
+        ::time::Weekday::Friday => 4,
+        ::time::Weekday::Saturday => 5,
+        ::time::Weekday::Sunday => 6,
+    }
+}
+
+/// The ISO 8601 weekday number, from Monday as 1 to Sunday as 7.
+pub const fn weekday_to_iso(weekday: ::time::Weekday) -> u8 {
+    iso_index_of(weekday) as u8 + 1
+}
+
+pub const fn weekday_from_iso(number: u8) -> Result<::time::Weekday, Iso8601SerdeError> {
+    match number {
+        1..=7 => Ok(WEEKDAYS[number as usize - 1]),
+        _ => Err(Iso8601SerdeError::OutOfRange {
+            name: "weekday",
+            minimum: 1,
+            maximum: 7,
+            value: number as i64,
+        }),
+    }
+}
+
+const DAYS_IN_MONTH: [u8; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
 ];
 
-pub const fn date_from_iso8601(
-    date: iso8601::Date,
-) -> Result<time::Date, time::error::ComponentRange> {
+/// The number of days in `month` of `year`, counting February 29 in leap years, or 0 for a
+/// month outside 1..=12.
+pub const fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if ::time::is_leap_year(year) => 29,
+        1..=12 => DAYS_IN_MONTH[month as usize - 1],
+        _ => 0,
+    }
+}
+
+pub const fn date_from_iso8601(date: iso8601::Date) -> Result<::time::Date, Iso8601SerdeError> {
     match date {
         iso8601::Date::YMD { year, month, day } => {
-            time::Date::try_from_ymd(year, month as u8, day as u8)
+            if month < 1 || month > 12 {
+                return Err(Iso8601SerdeError::OutOfRange {
+                    name: "month",
+                    minimum: 1,
+                    maximum: 12,
+                    value: month as i64,
+                });
+            }
+            let days = days_in_month(year, month as u8);
+            if day < 1 {
+                return Err(Iso8601SerdeError::OutOfRange {
+                    name: "day",
+                    minimum: 1,
+                    maximum: days as i64,
+                    value: day as i64,
+                });
+            }
+            if day > days as u32 {
+                return Err(Iso8601SerdeError::DayOfMonth {
+                    year,
+                    month: month as u8,
+                    day,
+                });
+            }
+            component_range(::time::Date::try_from_ymd(year, month as u8, day as u8))
         }
         iso8601::Date::Week { year, ww, d } => {
-            time::Date::try_from_iso_ywd(year, ww as u8, WEEKDAYS[d as usize - 1])
+            if ww < 1 || ww > 53 {
+                return Err(Iso8601SerdeError::OutOfRange {
+                    name: "week",
+                    minimum: 1,
+                    maximum: 53,
+                    value: ww as i64,
+                });
+            }
+            if d < 1 || d > 7 {
+                return Err(Iso8601SerdeError::OutOfRange {
+                    name: "weekday",
+                    minimum: 1,
+                    maximum: 7,
+                    value: d as i64,
+                });
+            }
+            component_range(::time::Date::try_from_iso_ywd(
+                year,
+                ww as u8,
+                WEEKDAYS[d as usize - 1],
+            ))
+        }
+        iso8601::Date::Ordinal { year, ddd } => {
+            if ddd < 1 || ddd > 366 {
+                return Err(Iso8601SerdeError::OutOfRange {
+                    name: "ordinal",
+                    minimum: 1,
+                    maximum: 366,
+                    value: ddd as i64,
+                });
+            }
+            if ddd == 366 && !::time::is_leap_year(year) {
+                return Err(Iso8601SerdeError::OutOfRange {
+                    name: "ordinal of a non-leap year",
+                    minimum: 1,
+                    maximum: 365,
+                    value: 366,
+                });
+            }
+            component_range(::time::Date::try_from_yo(year, ddd as u16))
+        }
+    }
+}
+
+/// `iso8601::Time` only holds milliseconds, so the nanoseconds are rounded to the nearest one,
+/// carrying into the seconds and minutes: `12:00:59.9996` becomes `12:01:00.000`. A time has no
+/// date to carry into, so one that would round up to midnight stays at `23:59:59.999`;
+/// [`datetime_to_iso8601`] rolls over into the next day instead.
+///
+/// The sign of the offset is carried by the hour field alone, so `-05:30` becomes
+/// `tz_offset_hours: -5, tz_offset_minutes: 30`. Only an offset of less than an hour, like
+/// `-00:30`, has the sign on the minute field, since there's no negative zero hour. Offset
+/// seconds are truncated since there is no field for them; [`try_time_to_iso8601`] rejects them
+/// instead. The serde modules don't go through `iso8601::Time` and keep them, writing offsets
+/// like `+00:53:28`.
+pub const fn time_to_iso8601(time: ::time::Time, offset: ::time::UtcOffset) -> iso8601::Time {
+    match rounded_time_to_iso8601(time, offset) {
+        (time, true) => end_of_day_millisecond(time),
+        (time, false) => time,
+    }
+}
+
+// `true` when the time rounded up to the midnight that ends the day.
+const fn rounded_time_to_iso8601(
+    time: ::time::Time,
+    offset: ::time::UtcOffset,
+) -> (iso8601::Time, bool) {
+    let (tz_offset_hours, tz_offset_minutes) = iso8601_offset_fields(offset.as_minutes() as i32);
+    round_to_millisecond(
+        iso8601::Time {
+            hour: time.hour() as u32,
+            minute: time.minute() as u32,
+            second: time.second() as u32,
+            millisecond: 0,
+            tz_offset_hours,
+            tz_offset_minutes,
+        },
+        time.nanosecond(),
+    )
+}
+
+// Rounds `nanosecond` to the nearest millisecond, half up, carrying into the seconds, minutes
+// and hours of `time`. Rounding up to the next midnight gives `00:00:00.000` and `true`, for the
+// caller to carry into the date. A leap second isn't rounded up, since there's no second 61.
+const fn round_to_millisecond(time: iso8601::Time, nanosecond: u32) -> (iso8601::Time, bool) {
+    let iso8601::Time {
+        mut hour,
+        mut minute,
+        mut second,
+        ..
+    } = time;
+    let mut millisecond = (nanosecond + 500_000) / 1_000_000;
+    if millisecond == 1_000 && second == 60 {
+        millisecond = 999;
+    } else if millisecond == 1_000 {
+        millisecond = 0;
+        second += 1;
+        if second == 60 {
+            second = 0;
+            minute += 1;
+            if minute == 60 {
+                minute = 0;
+                hour += 1;
+            }
         }
-        iso8601::Date::Ordinal { year, ddd } => time::Date::try_from_yo(year, ddd as u16),
     }
+    let next_day = hour == 24 && time.hour == 23;
+    let time = iso8601::Time {
+        hour: if next_day { 0 } else { hour },
+        minute,
+        second,
+        millisecond,
+        ..time
+    };
+    (time, next_day)
 }
 
-pub const fn time_to_iso8601(time: time::Time, offset: time::UtcOffset) -> iso8601::Time {
-    let offset_minutes = offset.as_minutes();
+const fn end_of_day_millisecond(time: iso8601::Time) -> iso8601::Time {
     iso8601::Time {
-        hour: time.hour() as u32,
-        minute: time.minute() as u32,
-        second: time.second() as u32,
-        millisecond: time.millisecond() as u32,
-        tz_offset_hours: offset_minutes as i32 / 60,
-        tz_offset_minutes: offset_minutes as i32 % 60,
+        hour: 23,
+        minute: 59,
+        second: 59,
+        millisecond: 999,
+        ..time
+    }
+}
+
+// The day after `date`, written by `date_to_iso8601`. `Date::next_day` isn't `const` and panics
+// after the last day of the year 100_000, which is still a valid `iso8601::Date`.
+const fn next_day_to_iso8601(date: ::time::Date) -> iso8601::Date {
+    let (year, month, day) = date.as_ymd();
+    let (year, month, day) = if day < days_in_month(year, month) {
+        (year, month, day + 1)
+    } else if month < 12 {
+        (year, month + 1, 1)
+    } else {
+        (year + 1, 1, 1)
+    };
+    iso8601::Date::YMD {
+        year,
+        month: month as u32,
+        day: day as u32,
+    }
+}
+
+const fn iso8601_offset_fields(offset_minutes: i32) -> (i32, i32) {
+    let (hours, minutes) = (offset_minutes / 60, offset_minutes % 60);
+    if hours == 0 {
+        (hours, minutes)
+    } else {
+        (hours, minutes.abs())
+    }
+}
+
+// The `iso8601` parser puts the sign on both fields, `-05:30` being `-5` and `-30`, while
+// `time_to_iso8601` leaves the minutes unsigned, so a negative hour makes either negative.
+// Computed in `i64`, since the fields are `i32` and `tz_offset_hours * 60` could overflow.
+const fn iso8601_offset_minutes(tz_offset_hours: i32, tz_offset_minutes: i32) -> i64 {
+    let hours = tz_offset_hours as i64 * 60;
+    if tz_offset_hours < 0 && tz_offset_minutes > 0 {
+        hours - tz_offset_minutes as i64
+    } else {
+        hours + tz_offset_minutes as i64
+    }
+}
+
+/// Like [`time_to_iso8601`], but rejects offsets with seconds instead of truncating them, and
+/// offsets beyond ±14:00.
+pub const fn try_time_to_iso8601(
+    time: ::time::Time,
+    offset: ::time::UtcOffset,
+) -> Result<iso8601::Time, Iso8601SerdeError> {
+    if let Err(err) = offset_in_range(offset) {
+        return Err(err);
+    }
+    match whole_minute_offset(offset) {
+        Ok(()) => Ok(time_to_iso8601(time, offset)),
+        Err(err) => Err(err),
+    }
+}
+
+// `UtcOffset::seconds` takes any value, so an offset like `+99:00` can reach the serializers.
+const fn offset_in_range(offset: ::time::UtcOffset) -> Result<(), Iso8601SerdeError> {
+    let seconds = offset.as_seconds();
+    if seconds < -14 * 3_600 || seconds > 14 * 3_600 {
+        return Err(Iso8601SerdeError::OutOfRange {
+            name: "UTC offset in seconds",
+            minimum: -14 * 3_600,
+            maximum: 14 * 3_600,
+            value: seconds as i64,
+        });
+    }
+    Ok(())
+}
+
+// `OffsetDateTime::to_offset` doesn't check that the local date stays within `time`'s range, and
+// `date()` then panics, like for `+100000-12-31T23:00:00Z` in `+14:00`. The serializers check
+// this and the offset range before writing a datetime.
+fn datetime_in_range(datetime: ::time::OffsetDateTime) -> Result<(), Iso8601SerdeError> {
+    offset_in_range(datetime.offset())?;
+    let timestamp = datetime.unix_timestamp_nanos()
+        + i128::from(datetime.offset().as_seconds()) * 1_000_000_000;
+    match datetime_from_unix_timestamp_nanos(timestamp) {
+        Some(_) => Ok(()),
+        None => Err(Iso8601SerdeError::OutOfRange {
+            name: "year",
+            minimum: -100_000,
+            maximum: 100_000,
+            value: if timestamp < 0 { -100_001 } else { 100_001 },
+        }),
+    }
+}
+
+const fn whole_minute_offset(offset: ::time::UtcOffset) -> Result<(), Iso8601SerdeError> {
+    if offset.as_seconds() % 60 != 0 {
+        return Err(Iso8601SerdeError::SubMinuteOffset);
     }
+    Ok(())
 }
 
+/// A leap second, `second == 60`, can't be represented by `time::Time` and is clamped to the
+/// last millisecond before it, so `23:59:60` becomes `23:59:59.999`. The offset is only checked
+/// to be within ±23:59, beyond which it is an error with a zero offset; see
+/// [`time_from_iso8601_checked`] for the full checks.
 pub const fn time_from_iso8601(
     iso8601::Time {
         hour,
@@ -62,191 +521,4181 @@ pub const fn time_from_iso8601(
         tz_offset_hours,
         tz_offset_minutes,
     }: iso8601::Time,
-) -> (
-    Result<time::Time, time::error::ComponentRange>,
-    time::UtcOffset,
-) {
+) -> (Result<::time::Time, Iso8601SerdeError>, ::time::UtcOffset) {
+    let offset_minutes = iso8601_offset_minutes(tz_offset_hours, tz_offset_minutes);
+    if offset_minutes < -(24 * 60 - 1) || offset_minutes > 24 * 60 - 1 {
+        let err = Iso8601SerdeError::OutOfRange {
+            name: "UTC offset in minutes",
+            minimum: -(24 * 60 - 1),
+            maximum: 24 * 60 - 1,
+            value: offset_minutes,
+        };
+        return (Err(err), ::time::UtcOffset::UTC);
+    }
+    let offset = ::time::UtcOffset::minutes(offset_minutes as i16);
+    if millisecond > 999 {
+        let err = Iso8601SerdeError::OutOfRange {
+            name: "millisecond",
+            minimum: 0,
+            maximum: 999,
+            value: millisecond as i64,
+        };
+        return (Err(err), offset);
+    }
+    let (second, millisecond) = if second == 60 {
+        (59, 999)
+    } else {
+        (second, millisecond)
+    };
     (
-        time::Time::try_from_hms_milli(hour as u8, minute as u8, second as u8, millisecond as u16),
-        time::UtcOffset::minutes(tz_offset_hours as i16 * 60 + tz_offset_minutes as i16),
+        time_component_range(::time::Time::try_from_hms_milli(
+            hour as u8,
+            minute as u8,
+            second as u8,
+            millisecond as u16,
+        )),
+        offset,
+    )
+}
+
+/// Like [`time_from_iso8601`], but also checks that the offset fields are in range, that the
+/// minutes of a positive offset aren't negative, and that the offset is within ISO 8601's
+/// `-14:00..=+14:00`. The minutes of a negative offset may carry the sign or not.
+pub const fn time_from_iso8601_checked(
+    time: iso8601::Time,
+) -> Result<(::time::Time, ::time::UtcOffset), Iso8601SerdeError> {
+    let iso8601::Time {
+        tz_offset_hours,
+        tz_offset_minutes,
+        ..
+    } = time;
+    if tz_offset_hours < -23 || tz_offset_hours > 23 {
+        return Err(Iso8601SerdeError::OutOfRange {
+            name: "offset hour",
+            minimum: -23,
+            maximum: 23,
+            value: tz_offset_hours as i64,
+        });
+    }
+    let (minimum, maximum) = match tz_offset_hours {
+        hours if hours > 0 => (0, 59),
+        hours if hours < 0 => (-59, 59),
+        _ => (-59, 59),
+    };
+    if tz_offset_minutes < minimum || tz_offset_minutes > maximum {
+        return Err(Iso8601SerdeError::OutOfRange {
+            name: "offset minute",
+            minimum: minimum as i64,
+            maximum: maximum as i64,
+            value: tz_offset_minutes as i64,
+        });
+    }
+    let minutes = iso8601_offset_minutes(tz_offset_hours, tz_offset_minutes);
+    if minutes < -14 * 60 || minutes > 14 * 60 {
+        return Err(Iso8601SerdeError::OutOfRange {
+            name: "UTC offset in minutes",
+            minimum: -14 * 60,
+            maximum: 14 * 60,
+            value: minutes,
+        });
+    }
+    match time_from_iso8601(time) {
+        (Ok(time), offset) => Ok((time, offset)),
+        (Err(err), _) => Err(err),
+    }
+}
+
+/// Keeps the wall-clock time in the offset of `datetime` rather than converting to UTC, so noon
+/// in `+05:30` is `12:00:00+05:30`. The offset fields are as in [`time_to_iso8601`].
+/// `iso8601::Time` stops at milliseconds, so the fraction is rounded to the nearest one, carrying
+/// as far as the date: `2020-01-01T23:59:59.9996` becomes `2020-01-02T00:00:00.000`.
+/// [`datetime_to_iso8601_parts`] truncates instead and keeps the remainder.
+///
+/// This can't be `const`: `OffsetDateTime::date` and `OffsetDateTime::time` convert from UTC to
+/// the offset and aren't `const` in `time` 0.2. [`datetime_parts_to_iso8601`] is, for a
+/// `PrimitiveDateTime` and offset known at compile time.
+pub fn datetime_to_iso8601(datetime: ::time::OffsetDateTime) -> iso8601::DateTime {
+    datetime_parts_to_iso8601(
+        ::time::PrimitiveDateTime::new(datetime.date(), datetime.time()),
+        datetime.offset(),
     )
 }
 
-pub fn datetime_to_iso8601(datetime: time::OffsetDateTime) -> iso8601::DateTime {
-    let date = datetime.date();
-    let time = datetime.time();
-    let offset = datetime.offset();
+/// Like [`datetime_to_iso8601`], but the fraction is truncated to the millisecond rather than
+/// rounded, and returned along with the nanoseconds below it that the `iso8601::DateTime` can't
+/// hold, in `0..1_000_000`. The millisecond times 1_000_000 plus these is the nanosecond of
+/// `datetime`.
+pub fn datetime_to_iso8601_parts(datetime: ::time::OffsetDateTime) -> (iso8601::DateTime, u32) {
+    let remainder = datetime.nanosecond() % 1_000_000;
+    let truncated = datetime - ::time::Duration::nanoseconds(remainder.into());
+    (datetime_to_iso8601(truncated), remainder)
+}
+
+/// The same instant as [`datetime_to_iso8601`], written in `offset` instead of the offset of
+/// `datetime`.
+pub fn datetime_to_iso8601_in_offset(
+    datetime: ::time::OffsetDateTime,
+    offset: ::time::UtcOffset,
+) -> iso8601::DateTime {
+    datetime_to_iso8601(datetime.to_offset(offset))
+}
+
+/// Like [`datetime_to_iso8601`], but rejects offsets with seconds instead of truncating them,
+/// offsets beyond ±14:00, and local years that [`try_date_to_iso8601`] rejects.
+pub fn try_datetime_to_iso8601(
+    datetime: ::time::OffsetDateTime,
+) -> Result<iso8601::DateTime, Iso8601SerdeError> {
+    datetime_in_range(datetime)?;
+    whole_minute_offset(datetime.offset())?;
+    try_date_to_iso8601(datetime.date())?;
+    Ok(datetime_to_iso8601(datetime))
+}
+
+/// Rejects an instant that is out of `time`'s range in UTC. This can't be `const`:
+/// `PrimitiveDateTime::assume_offset` isn't `const` in `time` 0.2. [`datetime_parts_from_iso8601`]
+/// is, and returns the local datetime and the offset apart.
+pub fn datetime_from_iso8601(
+    datetime: iso8601::DateTime,
+) -> Result<::time::OffsetDateTime, Iso8601SerdeError> {
+    let (datetime, offset) = datetime_parts_from_iso8601(datetime)?;
+    assume_offset_checked(datetime, offset)
+}
+
+/// Whether two datetimes are the same instant, whatever offsets they're in, so
+/// `2020-01-01T02:00:00+02:00` and `2020-01-01T00:00:00Z` are. This is what `==` on
+/// `OffsetDateTime` already does; the name only makes the intent explicit.
+pub fn same_instant(a: &::time::OffsetDateTime, b: &::time::OffsetDateTime) -> bool {
+    a == b
+}
+
+/// Whether two datetimes show the same date and time of day, ignoring their offsets, so
+/// `2020-01-01T00:00:00+02:00` and `2020-01-01T00:00:00Z` are.
+pub fn same_wall_clock(a: &::time::OffsetDateTime, b: &::time::OffsetDateTime) -> bool {
+    a.date() == b.date() && a.time() == b.time()
+}
+
+/// The `const` part of [`datetime_to_iso8601`], since `OffsetDateTime`'s local date and time
+/// aren't `const` in `time` 0.2.
+pub const fn datetime_parts_to_iso8601(
+    datetime: ::time::PrimitiveDateTime,
+    offset: ::time::UtcOffset,
+) -> iso8601::DateTime {
+    match rounded_time_to_iso8601(datetime.time(), offset) {
+        (time, true) => iso8601::DateTime {
+            date: next_day_to_iso8601(datetime.date()),
+            time,
+        },
+        (time, false) => iso8601::DateTime {
+            date: date_to_iso8601(datetime.date()),
+            time,
+        },
+    }
+}
+
+/// The `const` part of [`datetime_from_iso8601`], since `PrimitiveDateTime::assume_offset`
+/// isn't `const` in `time` 0.2.
+pub const fn datetime_parts_from_iso8601(
+    iso8601::DateTime { date, time }: iso8601::DateTime,
+) -> Result<(::time::PrimitiveDateTime, ::time::UtcOffset), Iso8601SerdeError> {
+    let date = match date_from_iso8601(date) {
+        Ok(date) => date,
+        Err(err) => return Err(err),
+    };
+    match time_from_iso8601_checked(time) {
+        Ok((time, offset)) => Ok((date.with_time(time), offset)),
+        Err(err) => Err(err),
+    }
+}
+
+pub const fn primitive_datetime_to_iso8601(
+    datetime: ::time::PrimitiveDateTime,
+) -> iso8601::DateTime {
+    datetime_parts_to_iso8601(datetime, ::time::UtcOffset::UTC)
+}
+
+pub const fn primitive_datetime_from_iso8601(
+    datetime: iso8601::DateTime,
+) -> Result<::time::PrimitiveDateTime, Iso8601SerdeError> {
+    match datetime_parts_from_iso8601(datetime) {
+        Ok((datetime, _)) => Ok(datetime),
+        Err(err) => Err(err),
+    }
+}
+
+fn try_convert_all<T: Copy, U>(
+    values: &[T],
+    convert: impl Fn(T) -> Result<U, Iso8601SerdeError>,
+) -> Result<Vec<U>, (usize, Iso8601SerdeError)> {
+    values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| convert(*value).map_err(|err| (index, err)))
+        .collect()
+}
+
+pub fn dates_to_iso8601(dates: &[::time::Date]) -> Vec<iso8601::Date> {
+    dates.iter().map(|date| date_to_iso8601(*date)).collect()
+}
+
+/// Converts each date with [`date_from_iso8601`], stopping at the first failure and returning
+/// its index along with the error.
+pub fn dates_from_iso8601(
+    dates: &[iso8601::Date],
+) -> Result<Vec<::time::Date>, (usize, Iso8601SerdeError)> {
+    try_convert_all(dates, date_from_iso8601)
+}
+
+pub fn datetimes_to_iso8601(datetimes: &[::time::OffsetDateTime]) -> Vec<iso8601::DateTime> {
+    datetimes
+        .iter()
+        .map(|datetime| datetime_to_iso8601(*datetime))
+        .collect()
+}
+
+/// Converts each datetime with [`datetime_from_iso8601`], stopping at the first failure and
+/// returning its index along with the error.
+pub fn datetimes_from_iso8601(
+    datetimes: &[iso8601::DateTime],
+) -> Result<Vec<::time::OffsetDateTime>, (usize, Iso8601SerdeError)> {
+    try_convert_all(datetimes, datetime_from_iso8601)
+}
+
+/// [`date_from_iso8601`] for a borrowed date, as handed out by `iter()`, so a slice converts with
+/// `dates.iter().map(date_from_iso8601_ref)`.
+pub const fn date_from_iso8601_ref(
+    date: &iso8601::Date,
+) -> Result<::time::Date, Iso8601SerdeError> {
+    date_from_iso8601(*date)
+}
+
+/// [`time_from_iso8601`] for a borrowed time.
+pub const fn time_from_iso8601_ref(
+    time: &iso8601::Time,
+) -> (Result<::time::Time, Iso8601SerdeError>, ::time::UtcOffset) {
+    time_from_iso8601(*time)
+}
+
+/// [`datetime_from_iso8601`] for a borrowed datetime.
+pub fn datetime_from_iso8601_ref(
+    datetime: &iso8601::DateTime,
+) -> Result<::time::OffsetDateTime, Iso8601SerdeError> {
+    datetime_from_iso8601(*datetime)
+}
+
+/// [`duration_from_iso8601`] for a borrowed duration.
+pub fn duration_from_iso8601_ref(
+    duration: &iso8601::Duration,
+) -> Result<::time::Duration, Iso8601SerdeError> {
+    duration_from_iso8601(*duration)
+}
+
+/// Bridges a `chrono` datetime into the `iso8601` representation, to go on to `time` with
+/// [`datetime_from_iso8601`]. As with [`datetime_to_iso8601`], the fraction is truncated to
+/// milliseconds and offset seconds are dropped. A `chrono` leap second is written as second 60.
+#[cfg(feature = "chrono")]
+pub fn chrono_to_iso8601(datetime: &chrono::DateTime<chrono::FixedOffset>) -> iso8601::DateTime {
+    use chrono::{Datelike, Timelike};
+
+    let (second, nanosecond) = match datetime.nanosecond() {
+        nanosecond @ 1_000_000_000.. => (60, nanosecond - 1_000_000_000),
+        nanosecond => (datetime.second(), nanosecond),
+    };
+    let (tz_offset_hours, tz_offset_minutes) =
+        iso8601_offset_fields(datetime.offset().local_minus_utc() / 60);
     iso8601::DateTime {
-        date: date_to_iso8601(date),
-        time: time_to_iso8601(time, offset),
+        date: iso8601::Date::YMD {
+            year: datetime.year(),
+            month: datetime.month(),
+            day: datetime.day(),
+        },
+        time: iso8601::Time {
+            hour: datetime.hour(),
+            minute: datetime.minute(),
+            second,
+            millisecond: nanosecond / 1_000_000,
+            tz_offset_hours,
+            tz_offset_minutes,
+        },
+    }
+}
+
+/// The other way from [`chrono_to_iso8601`], with the same checks as [`datetime_from_iso8601`].
+#[cfg(feature = "chrono")]
+pub fn iso8601_to_chrono(
+    datetime: iso8601::DateTime,
+) -> Result<chrono::DateTime<chrono::FixedOffset>, Iso8601SerdeError> {
+    use chrono::TimeZone;
+
+    let datetime = datetime_from_iso8601(datetime)?;
+    let (year, ordinal) = datetime.date().as_yo();
+    // `chrono` covers every date and offset `time` does, so these can't fail.
+    let local = chrono::NaiveDate::from_yo_opt(year, ordinal.into()).and_then(|date| {
+        date.and_hms_nano_opt(
+            datetime.hour().into(),
+            datetime.minute().into(),
+            datetime.second().into(),
+            datetime.nanosecond(),
+        )
+    });
+    let offset = chrono::FixedOffset::east_opt(datetime.offset().as_seconds());
+    match (local, offset) {
+        (Some(local), Some(offset)) => offset
+            .from_local_datetime(&local)
+            .single()
+            .ok_or(Iso8601SerdeError::Parse("datetime")),
+        _ => Err(Iso8601SerdeError::Parse("datetime")),
+    }
+}
+
+// The most days an `iso8601::Duration` holds, about 11.7 million years. [`duration::serialize`]
+// writes nothing longer, in either direction, since [`duration::deserialize`] couldn't read it.
+const MAX_DURATION_DAYS: i64 = u32::MAX as i64;
+
+pub fn duration_to_iso8601(
+    duration: ::time::Duration,
+) -> Result<iso8601::Duration, Iso8601SerdeError> {
+    let seconds = duration.whole_seconds();
+    if duration.is_negative() || seconds / 86_400 > MAX_DURATION_DAYS {
+        return Err(Iso8601SerdeError::DurationRange);
+    }
+    Ok(iso8601::Duration::YMDHMS {
+        year: 0,
+        month: 0,
+        day: (seconds / 86_400) as u32,
+        hour: (seconds % 86_400 / 3_600) as u32,
+        minute: (seconds % 3_600 / 60) as u32,
+        second: (seconds % 60) as u32,
+        millisecond: duration.subsec_milliseconds() as u32,
+    })
+}
+
+/// Years and months are rejected, since `time::Duration` only holds exact lengths.
+pub fn duration_from_iso8601(
+    duration: iso8601::Duration,
+) -> Result<::time::Duration, Iso8601SerdeError> {
+    match duration {
+        iso8601::Duration::YMDHMS {
+            year: 0,
+            month: 0,
+            day,
+            hour,
+            minute,
+            second,
+            millisecond,
+        } => Ok(::time::Duration::days(day.into())
+            + ::time::Duration::hours(hour.into())
+            + ::time::Duration::minutes(minute.into())
+            + ::time::Duration::seconds(second.into())
+            + ::time::Duration::milliseconds(millisecond.into())),
+        iso8601::Duration::YMDHMS { .. } => Err(Iso8601SerdeError::NominalDuration),
+        iso8601::Duration::Weeks(weeks) => Ok(::time::Duration::weeks(weeks.into())),
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Fraction {
+    /// As many groups of three digits as it takes to stay lossless, and at least one.
+    Lossless,
+    /// Exactly this many digits, truncating the rest. `Fixed(0)` writes no fraction at all.
+    Fixed(u32),
+    /// Only the significant digits, and nothing when the fraction is zero.
+    Trimmed,
+}
+
+// Exactly `N` fractional digits, checked when the constant is used, so `N` above 9 is a compile
+// error rather than a panic.
+struct FixedDigits<const N: usize>;
+
+impl<const N: usize> FixedDigits<N> {
+    const FRACTION: Fraction = {
+        assert!(N <= 9, "at most 9 fractional digits are supported");
+        Fraction::Fixed(N as u32)
+    };
+}
+
+fn write_fraction(f: &mut fmt::Formatter<'_>, nanosecond: u32, fraction: Fraction) -> fmt::Result {
+    match fraction {
+        Fraction::Lossless if nanosecond % 1_000_000 == 0 => {
+            write!(f, ".{:03}", nanosecond / 1_000_000)
+        }
+        Fraction::Lossless if nanosecond % 1_000 == 0 => {
+            write!(f, ".{:06}", nanosecond / 1_000)
+        }
+        Fraction::Lossless => write!(f, ".{:09}", nanosecond),
+        Fraction::Fixed(0) => Ok(()),
+        Fraction::Fixed(digits) => write!(
+            f,
+            ".{:0width$}",
+            nanosecond / 10u32.pow(9 - digits),
+            width = digits as usize
+        ),
+        Fraction::Trimmed if nanosecond == 0 => Ok(()),
+        Fraction::Trimmed => {
+            let mut digits = 9;
+            let mut nanosecond = nanosecond;
+            while nanosecond % 10 == 0 {
+                nanosecond /= 10;
+                digits -= 1;
+            }
+            write!(f, ".{:0width$}", nanosecond, width = digits)
+        }
+    }
+}
+
+// Years outside 0..=9999 are written with a sign, like `-0001` and `+10000`.
+struct YearDisplay(i32);
+
+impl fmt::Display for YearDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            year @ 0..=9999 => write!(f, "{:04}", year),
+            year => write!(f, "{:+05}", year),
+        }
+    }
+}
+
+// `iso8601::Date` writes week dates without the `W` and with a two-digit weekday, and doesn't
+// pad negative years.
+struct DateDisplay(iso8601::Date);
+
+impl fmt::Display for DateDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            iso8601::Date::YMD { year, month, day } => {
+                write!(f, "{}-{:02}-{:02}", YearDisplay(year), month, day)
+            }
+            iso8601::Date::Week { year, ww, d } => {
+                write!(f, "{}-W{:02}-{}", YearDisplay(year), ww, d)
+            }
+            iso8601::Date::Ordinal { year, ddd } => {
+                write!(f, "{}-{:03}", YearDisplay(year), ddd)
+            }
+        }
+    }
+}
+
+struct BasicDateDisplay(::time::Date);
+
+impl fmt::Display for BasicDateDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (year, month, day) = self.0.as_ymd();
+        write!(f, "{}{:02}{:02}", YearDisplay(year), month, day)
+    }
+}
+
+struct TimeDisplay(::time::Time, Option<OffsetDisplay>, Fraction);
+
+impl fmt::Display for TimeDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self(time, offset, fraction) = *self;
+        write!(
+            f,
+            "{:02}:{:02}:{:02}",
+            time.hour(),
+            time.minute(),
+            time.second()
+        )?;
+        // `iso8601::Time` stops at milliseconds, so the fraction is written from `time::Time`.
+        write_fraction(f, time.nanosecond(), fraction)?;
+        match offset {
+            Some(offset) => offset.fmt(f),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct OffsetDisplay {
+    offset: ::time::UtcOffset,
+    /// Writes UTC as `Z` rather than `+00:00`.
+    zulu: bool,
+}
+
+impl fmt::Display for OffsetDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.zulu && self.offset == ::time::UtcOffset::UTC {
+            return f.write_str("Z");
+        }
+        let seconds = self.offset.as_seconds();
+        let sign = if seconds < 0 { '-' } else { '+' };
+        let seconds = seconds.abs();
+        write!(
+            f,
+            "{}{:02}:{:02}",
+            sign,
+            seconds / 3_600,
+            seconds % 3_600 / 60
+        )?;
+        // Seconds are only written when there are any, as in historical offsets like `+00:53:28`.
+        if seconds % 60 != 0 {
+            write!(f, ":{:02}", seconds % 60)?;
+        }
+        Ok(())
+    }
+}
+
+struct DateTimeDisplay(::time::PrimitiveDateTime, Option<OffsetDisplay>, Fraction);
+
+impl DateTimeDisplay {
+    fn zulu(mut self) -> Self {
+        if let Some(offset) = &mut self.1 {
+            offset.zulu = true;
+        }
+        self
+    }
+
+    fn fraction(mut self, fraction: Fraction) -> Self {
+        self.2 = fraction;
+        self
+    }
+}
+
+impl From<::time::OffsetDateTime> for DateTimeDisplay {
+    fn from(datetime: ::time::OffsetDateTime) -> Self {
+        Self(
+            ::time::PrimitiveDateTime::new(datetime.date(), datetime.time()),
+            Some(OffsetDisplay {
+                offset: datetime.offset(),
+                zulu: false,
+            }),
+            Fraction::Lossless,
+        )
+    }
+}
+
+impl fmt::Display for DateTimeDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self(datetime, offset, fraction) = *self;
+        write!(
+            f,
+            "{}T{}",
+            DateDisplay(date_to_iso8601(datetime.date())),
+            TimeDisplay(datetime.time(), offset, fraction)
+        )
+    }
+}
+
+struct DurationDisplay(::time::Duration);
+
+impl fmt::Display for DurationDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self(mut duration) = *self;
+        if duration.is_zero() {
+            return f.write_str("PT0S");
+        }
+        if duration.is_negative() {
+            f.write_str("-")?;
+            duration = ::time::Duration::zero()
+                .checked_sub(duration)
+                .ok_or(fmt::Error)?;
+        }
+        let seconds = duration.whole_seconds();
+        let nanosecond = duration.subsec_nanoseconds() as u32;
+        f.write_str("P")?;
+        if seconds >= 86_400 {
+            write!(f, "{}D", seconds / 86_400)?;
+        }
+        if seconds % 86_400 == 0 && nanosecond == 0 {
+            return Ok(());
+        }
+        f.write_str("T")?;
+        if seconds % 86_400 >= 3_600 {
+            write!(f, "{}H", seconds % 86_400 / 3_600)?;
+        }
+        if seconds % 3_600 >= 60 {
+            write!(f, "{}M", seconds % 3_600 / 60)?;
+        }
+        if seconds % 60 != 0 || nanosecond != 0 {
+            write!(f, "{}", seconds % 60)?;
+            if nanosecond != 0 {
+                write_fraction(f, nanosecond, Fraction::Lossless)?;
+            }
+            f.write_str("S")?;
+        }
+        Ok(())
+    }
+}
+
+// The `iso8601` parser truncates fractional seconds to milliseconds, so the digits are read
+// from the source string again. Dates never contain `.` or `,`, so the first one found always
+// starts the fraction.
+fn fraction_nanosecond(s: &str) -> u32 {
+    let fraction = match s.find(['.', ',']) {
+        Some(index) => &s[index + 1..],
+        None => return 0,
+    };
+    let mut nanosecond = 0;
+    let mut scale = 100_000_000;
+    for digit in fraction.bytes().take_while(u8::is_ascii_digit).take(9) {
+        nanosecond += u32::from(digit - b'0') * scale;
+        scale /= 10;
+    }
+    nanosecond
+}
+
+// A leap second is clamped to the last nanosecond before it, like `time_from_iso8601` does to
+// the millisecond.
+fn time_from_iso8601_str(
+    time: iso8601::Time,
+    s: &str,
+) -> Result<(::time::Time, ::time::UtcOffset), Iso8601SerdeError> {
+    let nanosecond = if time.second == 60 {
+        999_999_999
+    } else {
+        fraction_nanosecond(s)
+    };
+    // The parser rounds the fraction to milliseconds, so `.9999` would come out as 1000.
+    let time = iso8601::Time {
+        millisecond: nanosecond / 1_000_000,
+        ..time
+    };
+    let offset_time = s.rfind('T').map_or(s, |t| &s[t..]);
+    check_offset_digits(offset_time)?;
+    let (time, offset) = time_from_iso8601_checked(time)?;
+    let time =
+        ::time::Time::try_from_hms_nano(time.hour(), time.minute(), time.second(), nanosecond)?;
+    Ok((time, with_offset_seconds(offset, offset_time)?))
+}
+
+// The `iso8601` parser only reads four-digit years. The Gregorian calendar repeats every 400
+// years, weekdays included, so an expanded year like `+10000` is swapped for the four-digit year
+// at the same point of the cycle, and the returned shift is added back after parsing. Only the
+// extended format is handled, since the length of an expanded year is ambiguous in the basic one.
+fn four_digit_year(s: &str) -> (i32, Cow<'_, str>) {
+    let digits = s.get(1..).map_or(0, |rest| {
+        rest.bytes().take_while(u8::is_ascii_digit).count()
+    });
+    if !s.starts_with(['+', '-']) || digits <= 4 {
+        return (0, Cow::Borrowed(s));
+    }
+    let (year, rest) = s.split_at(1 + digits);
+    match year.parse::<i32>() {
+        Ok(year) if rest.starts_with('-') => {
+            let cycle_year = 2000 + year.rem_euclid(400);
+            (
+                year - cycle_year,
+                Cow::Owned(format!("{}{}", cycle_year, rest)),
+            )
+        }
+        _ => (0, Cow::Borrowed(s)),
+    }
+}
+
+fn shift_year(date: iso8601::Date, shift: i32) -> iso8601::Date {
+    match date {
+        iso8601::Date::YMD { year, month, day } => iso8601::Date::YMD {
+            year: year + shift,
+            month,
+            day,
+        },
+        iso8601::Date::Week { year, ww, d } => iso8601::Date::Week {
+            year: year + shift,
+            ww,
+            d,
+        },
+        iso8601::Date::Ordinal { year, ddd } => iso8601::Date::Ordinal {
+            year: year + shift,
+            ddd,
+        },
+    }
+}
+
+/// Parses a datetime as far as [`iso8601::DateTime`], with the same input checks as
+/// [`parse_datetime`], to inspect it before [`datetime_from_iso8601`]. The fraction is rounded
+/// to the nearest millisecond as in [`datetime_to_iso8601`], carrying into the date in the form
+/// it was written in. Offset seconds and the `24:00:00` roll-over are left out, since the
+/// `iso8601` types have no room for them.
+pub fn parse_iso8601_datetime(s: &str) -> Result<iso8601::DateTime, Iso8601SerdeError> {
+    let iso8601::DateTime { date, time } = parse_iso8601_datetime_truncated(s)?;
+    let (time, next_day) = round_to_millisecond(time, fraction_nanosecond(s));
+    if !next_day {
+        return Ok(iso8601::DateTime { date, time });
+    }
+    let day = date_from_iso8601(date)?;
+    if day == ::time::Date::try_from_ymd(100_000, 12, 31)? {
+        return Ok(iso8601::DateTime {
+            date,
+            time: end_of_day_millisecond(time),
+        });
+    }
+    Ok(iso8601::DateTime {
+        date: date_to_iso8601_as(day.next_day(), iso8601_date_kind(&date)),
+        time,
+    })
+}
+
+// The fraction truncated to the millisecond, for the parsers that read the full nanoseconds
+// from the string afterwards and need the seconds as written.
+fn parse_iso8601_datetime_truncated(s: &str) -> Result<iso8601::DateTime, Iso8601SerdeError> {
+    if let Some(t) = s.find('T') {
+        single_zone_designator(&s[t..])?;
+    }
+    let (shift, shifted) = four_digit_year(s);
+    if !is_datetime_shape(&shifted) {
+        return Err(Iso8601SerdeError::Parse("datetime"));
+    }
+    let iso8601::DateTime { date, time } = iso8601::datetime(&shifted)
+        .ok()
+        .or_else(|| iso8601_week_datetime(&shifted))
+        .ok_or(Iso8601SerdeError::Parse("datetime"))?;
+    Ok(iso8601::DateTime {
+        date: shift_year(date, shift),
+        time: iso8601::Time {
+            millisecond: fraction_nanosecond(s) / 1_000_000,
+            ..time
+        },
+    })
+}
+
+fn parse_primitive_datetime(
+    s: &str,
+) -> Result<(::time::PrimitiveDateTime, ::time::UtcOffset), Iso8601SerdeError> {
+    let iso8601::DateTime { date, time } = parse_iso8601_datetime_truncated(s)?;
+    let date = date_from_iso8601(date)?;
+    if is_end_of_day(time, s) {
+        let (_, offset) = time_from_iso8601_checked(iso8601::Time { hour: 0, ..time })?;
+        if date == ::time::Date::try_from_ymd(100_000, 12, 31)? {
+            return Err(Iso8601SerdeError::OutOfRange {
+                name: "year",
+                minimum: -100_000,
+                maximum: 100_000,
+                value: 100_001,
+            });
+        }
+        return Ok((date.next_day().midnight(), offset));
+    }
+    let (time, offset) = time_from_iso8601_str(time, s)?;
+    Ok((date.with_time(time), offset))
+}
+
+// ISO 8601 allows `24:00:00` for the midnight that ends a day, which `time::Time` can't hold.
+fn is_end_of_day(time: iso8601::Time, s: &str) -> bool {
+    time.hour == 24 && time.minute == 0 && time.second == 0 && fraction_nanosecond(s) == 0
+}
+
+/// Parses a datetime the way [`datetime::deserialize`] does. The end-of-day time `24:00:00` is
+/// read as midnight at the start of the next day, so `2020-01-01T24:00:00Z` is
+/// `2020-01-02T00:00:00Z`.
+///
+/// A datetime without a zone designator is rejected with [`Iso8601SerdeError::MissingOffset`],
+/// since its instant is unknown; [`parse_datetime_assuming_utc`] reads it as UTC instead. RFC
+/// 3339's `-00:00`, an unknown local offset, is read as UTC just like `+00:00`, since
+/// `time::UtcOffset` has no negative zero. [`has_unknown_local_offset`] tells the two apart.
+pub fn parse_datetime(s: &str) -> Result<::time::OffsetDateTime, Iso8601SerdeError> {
+    let (datetime, offset) = parse_primitive_datetime(s)?;
+    if !has_zone_designator(s) {
+        return Err(Iso8601SerdeError::MissingOffset);
+    }
+    assume_offset_checked(datetime, offset)
+}
+
+/// Parses a datetime the way [`datetime::assume_utc::deserialize`] does: like [`parse_datetime`],
+/// but a datetime without a zone designator, like `2020-01-01T12:00:00`, is read as UTC.
+pub fn parse_datetime_assuming_utc(s: &str) -> Result<::time::OffsetDateTime, Iso8601SerdeError> {
+    let (datetime, offset) = parse_primitive_datetime(s)?;
+    assume_offset_checked(datetime, offset)
+}
+
+/// Whether the string ends in the offset `-00:00`, or `-0000` or `-00`, which RFC 3339 uses for
+/// a UTC time whose local offset is unknown.
+pub fn has_unknown_local_offset(s: &str) -> bool {
+    let time = match s.find('T') {
+        Some(t) => &s[t..],
+        None => return false,
+    };
+    time.rfind('-').is_some_and(|sign| {
+        matches!(
+            &time[sign..],
+            "-00:00" | "-0000" | "-00" | "-00:00:00" | "-000000"
+        )
+    })
+}
+
+// The `iso8601` parser reads a missing offset as `+00:00`, so the zone designator is looked
+// for after the `T` instead.
+fn has_zone_designator(s: &str) -> bool {
+    s.find('T')
+        .is_some_and(|t| s[t..].contains(['Z', '+', '-']))
+}
+
+// The hour, minute and second digits of a numeric offset at the end of `time`, and where its
+// sign is. The `iso8601` parser reads minutes above 59 as zero, and an offset it can't read at
+// all, like `+99:00` or `+9`, as no offset, so they're checked from here.
+fn offset_digits(time: &str) -> Option<(usize, &str, Option<&str>, Option<&str>)> {
+    let sign = time.rfind(['+', '-'])?;
+    let zone = &time[sign..];
+    let digit = |range: core::ops::Range<usize>| zone.get(range).filter(|s| is_digits(s, 2));
+    let colon = zone.as_bytes().get(3) == Some(&b':');
+    let (minutes, seconds) = match (zone.len(), colon) {
+        (3, _) => (None, None),
+        (5, false) => (Some(3..5), None),
+        (6, true) => (Some(4..6), None),
+        (7, false) => (Some(3..5), Some(5..7)),
+        (9, true) => (Some(4..6), Some(7..9)),
+        _ => return None,
+    };
+    Some((
+        sign,
+        digit(1..3)?,
+        minutes.and_then(digit),
+        seconds.and_then(digit),
+    ))
+}
+
+fn check_offset_digits(time: &str) -> Result<(), Iso8601SerdeError> {
+    if !time.contains(['+', '-']) {
+        return Ok(());
+    }
+    let (sign, hours, minutes, _) =
+        offset_digits(time).ok_or(Iso8601SerdeError::Parse("UTC offset"))?;
+    let hours: i64 = hours.parse().unwrap_or_default();
+    if hours > 23 {
+        return Err(Iso8601SerdeError::OutOfRange {
+            name: "offset hour",
+            minimum: -23,
+            maximum: 23,
+            value: if time[sign..].starts_with('-') {
+                -hours
+            } else {
+                hours
+            },
+        });
+    }
+    match minutes.map(|minutes| minutes.parse::<i64>().unwrap_or_default()) {
+        Some(minutes) if minutes > 59 => Err(Iso8601SerdeError::OutOfRange {
+            name: "offset minute",
+            minimum: 0,
+            maximum: 59,
+            value: minutes,
+        }),
+        _ => Ok(()),
+    }
+}
+
+// The `iso8601` parser has no offset seconds and ignores them as trailing input, so they're read
+// from the end of the string here: `±HH:MM:SS`, or `±HHMMSS` in the basic format.
+fn with_offset_seconds(
+    offset: ::time::UtcOffset,
+    time: &str,
+) -> Result<::time::UtcOffset, Iso8601SerdeError> {
+    let sign = match time.rfind(['+', '-']) {
+        Some(sign) => sign,
+        None => return Ok(offset),
+    };
+    let zone = &time[sign..];
+    let seconds = match zone.len() {
+        9 if zone.as_bytes()[6] == b':' => &zone[7..],
+        7 if is_digits(&zone[1..], 6) => &zone[5..],
+        _ => return Ok(offset),
+    };
+    if !is_digits(seconds, 2) {
+        return Err(Iso8601SerdeError::Parse("UTC offset"));
+    }
+    let seconds: i32 = seconds.parse().unwrap_or_default();
+    if seconds > 59 {
+        return Err(Iso8601SerdeError::OutOfRange {
+            name: "offset second",
+            minimum: 0,
+            maximum: 59,
+            value: seconds.into(),
+        });
+    }
+    let seconds = if zone.starts_with('-') {
+        -seconds
+    } else {
+        seconds
+    };
+    // `+14:00` passes the range check on hours and minutes, but `+14:00:30` is still out of range.
+    let offset = ::time::UtcOffset::seconds(offset.as_seconds() + seconds);
+    offset_in_range(offset)?;
+    Ok(offset)
+}
+
+// The `iso8601` parser stops after a `Z`, so a numeric offset following it would be ignored.
+fn single_zone_designator(time: &str) -> Result<(), Iso8601SerdeError> {
+    if time.contains('Z') && time.contains(['+', '-']) {
+        return Err(Iso8601SerdeError::ConflictingOffset);
+    }
+    Ok(())
+}
+
+fn is_digits(s: &str, len: usize) -> bool {
+    s.len() == len && s.bytes().all(|b| b.is_ascii_digit())
+}
+
+// The `iso8601` parser stops where its grammar does and ignores whatever follows, so
+// `2020-01-01x` and `12:00:00garbage` would parse. Every date, time and datetime is checked
+// against these shapes as a whole before it's handed to the parser.
+fn is_date_shape(s: &str) -> bool {
+    let s = match skip_digits(s.strip_prefix(['+', '-']).unwrap_or(s), 4) {
+        Some(s) => skip_char(s, '-'),
+        None => return false,
+    };
+    if let Some(week) = s.strip_prefix('W') {
+        return skip_digits(week, 2).and_then(|s| skip_digits(skip_char(s, '-'), 1)) == Some("");
+    }
+    let ymd = skip_digits(s, 2).and_then(|s| skip_digits(skip_char(s, '-'), 2));
+    ymd == Some("") || skip_digits(s, 3) == Some("")
+}
+
+// `HH[:]MM[[:]SS][.fraction][Z|±HH[[:]MM[[:]SS]]]`, with offset seconds, which the parser has
+// no room for, read by `with_offset_seconds`.
+fn is_time_shape(s: &str) -> bool {
+    let s = match skip_digits(s, 2).and_then(|s| skip_digits(skip_char(s, ':'), 2)) {
+        Some(s) => s,
+        None => return false,
+    };
+    // The parser takes seconds up to 60, a leap second, and leaves larger ones unread as if
+    // there were none, so `23:59:61` would be `23:59:00`.
+    let seconds = skip_char(s, ':');
+    let s = match skip_digits(seconds, 2) {
+        Some(_) if &seconds[..2] > "60" => return false,
+        Some(rest) => rest,
+        None => s,
+    };
+    let s = match s.strip_prefix(['.', ',']) {
+        Some(fraction) => match fraction.bytes().take_while(u8::is_ascii_digit).count() {
+            0 => return false,
+            digits => &fraction[digits..],
+        },
+        None => s,
+    };
+    if s.is_empty() || s == "Z" {
+        return true;
+    }
+    let mut offset = match s.strip_prefix(['+', '-']).and_then(|s| skip_digits(s, 2)) {
+        Some(offset) => offset,
+        None => return false,
+    };
+    for _ in 0..2 {
+        if offset.is_empty() {
+            return true;
+        }
+        offset = match skip_digits(skip_char(offset, ':'), 2) {
+            Some(offset) => offset,
+            None => return false,
+        };
+    }
+    offset.is_empty()
+}
+
+fn is_datetime_shape(s: &str) -> bool {
+    s.split_once('T')
+        .is_some_and(|(date, time)| is_date_shape(date) && is_time_shape(time))
+}
+
+fn skip_digits(s: &str, len: usize) -> Option<&str> {
+    match s.get(..len) {
+        Some(digits) if is_digits(digits, len) => Some(&s[len..]),
+        _ => None,
+    }
+}
+
+fn skip_char(s: &str, c: char) -> &str {
+    s.strip_prefix(c).unwrap_or(s)
+}
+
+// RFC 3339's profile of ISO 8601: a calendar date, `T`, a time with seconds and an optional
+// fraction, then `Z` or an extended `+HH:MM` offset.
+fn is_rfc3339(s: &str) -> bool {
+    if !s.is_ascii() || s.len() < 20 {
+        return false;
+    }
+    let (datetime, rest) = s.split_at(19);
+    let b = datetime.as_bytes();
+    let shape = is_digits(&datetime[..4], 4)
+        && b[4] == b'-'
+        && is_digits(&datetime[5..7], 2)
+        && b[7] == b'-'
+        && is_digits(&datetime[8..10], 2)
+        && b[10] == b'T'
+        && is_digits(&datetime[11..13], 2)
+        && b[13] == b':'
+        && is_digits(&datetime[14..16], 2)
+        && b[16] == b':'
+        && is_digits(&datetime[17..19], 2);
+    let offset = match rest.strip_prefix('.') {
+        Some(fraction) => {
+            let digits = fraction.bytes().take_while(u8::is_ascii_digit).count();
+            if digits == 0 {
+                return false;
+            }
+            &fraction[digits..]
+        }
+        None => rest,
+    };
+    shape
+        && (offset == "Z"
+            || offset.len() == 6
+                && offset.starts_with(['+', '-'])
+                && is_digits(&offset[1..3], 2)
+                && offset.as_bytes()[3] == b':'
+                && is_digits(&offset[4..], 2))
+}
+
+// The `iso8601` parser stops at week 52, so week dates are read here as well to reach week 53.
+fn iso8601_week_date(s: &str) -> Option<iso8601::Date> {
+    let (year, rest) = s.split_at(s.find('W')?);
+    let year = year.strip_suffix('-').unwrap_or(year);
+    let ww = rest.get(1..3)?;
+    let d = rest.get(3..)?;
+    let d = d.strip_prefix('-').unwrap_or(d);
+    if !is_digits(year.strip_prefix(['+', '-']).unwrap_or(year), 4)
+        || !is_digits(ww, 2)
+        || !is_digits(d, 1)
+    {
+        return None;
+    }
+    Some(iso8601::Date::Week {
+        year: year.parse().ok()?,
+        ww: ww.parse().ok()?,
+        d: d.parse().ok()?,
+    })
+}
+
+// Week 53 as in `iso8601_week_date`, followed by a time.
+fn iso8601_week_datetime(s: &str) -> Option<iso8601::DateTime> {
+    let t = s.find('T')?;
+    Some(iso8601::DateTime {
+        date: iso8601_week_date(&s[..t])?,
+        time: iso8601::time(&s[t + 1..]).ok()?,
+    })
+}
+
+/// Parses a date the way [`date::deserialize`] does, accepting any ISO date form.
+pub fn parse_date(s: &str) -> Result<::time::Date, Iso8601SerdeError> {
+    date_from_iso8601(parse_iso8601_date(s)?)
+}
+
+/// Parses a date as far as [`iso8601::Date`], like [`parse_date`] but without the range checks
+/// of [`date_from_iso8601`], so the form it was written in can be inspected.
+pub fn parse_iso8601_date(s: &str) -> Result<iso8601::Date, Iso8601SerdeError> {
+    let (shift, s) = four_digit_year(s);
+    if !is_date_shape(&s) {
+        return Err(Iso8601SerdeError::Parse("date"));
+    }
+    let date = iso8601::date(&s)
+        .or_else(|err| iso8601_week_date(&s).ok_or(err))
+        .map_err(|_| Iso8601SerdeError::Parse("date"))?;
+    Ok(shift_year(date, shift))
+}
+
+/// Parses a time and its offset the way [`time_offset::deserialize`] does. `24:00:00` is
+/// rejected with [`Iso8601SerdeError::EndOfDay`], since there is no date to roll over into.
+pub fn parse_time_offset(s: &str) -> Result<(::time::Time, ::time::UtcOffset), Iso8601SerdeError> {
+    let time = parse_iso8601_time_truncated(s)?;
+    if is_end_of_day(time, s) {
+        return Err(Iso8601SerdeError::EndOfDay);
+    }
+    time_from_iso8601_str(time, s)
+}
+
+/// Parses a time as far as [`iso8601::Time`], with the same input checks as
+/// [`parse_time_offset`]. The fraction is rounded to the nearest millisecond as in
+/// [`time_to_iso8601`], and offset seconds are left out, as in [`parse_iso8601_datetime`].
+pub fn parse_iso8601_time(s: &str) -> Result<iso8601::Time, Iso8601SerdeError> {
+    let time = parse_iso8601_time_truncated(s)?;
+    match round_to_millisecond(time, fraction_nanosecond(s)) {
+        (time, true) => Ok(end_of_day_millisecond(time)),
+        (time, false) => Ok(time),
+    }
+}
+
+fn parse_iso8601_time_truncated(s: &str) -> Result<iso8601::Time, Iso8601SerdeError> {
+    // A datetime isn't a time, and its date would otherwise be taken for an offset after a `Z`.
+    if s.contains('T') {
+        return Err(Iso8601SerdeError::Parse("time"));
+    }
+    single_zone_designator(s)?;
+    if !is_time_shape(s) {
+        return Err(Iso8601SerdeError::Parse("time"));
+    }
+    let time = iso8601::time(s).map_err(|_| Iso8601SerdeError::Parse("time"))?;
+    Ok(iso8601::Time {
+        millisecond: fraction_nanosecond(s) / 1_000_000,
+        ..time
+    })
+}
+
+// `HH:MM` already parses, with the seconds as zero; an hour alone, like `12` or `12+09:00`, gets
+// its minutes filled in first.
+fn parse_reduced_time_offset(
+    s: &str,
+) -> Result<(::time::Time, ::time::UtcOffset), Iso8601SerdeError> {
+    let hour_only = is_digits(s.get(..2).unwrap_or(""), 2)
+        && matches!(s.as_bytes().get(2), None | Some(b'Z' | b'+' | b'-'));
+    if hour_only {
+        parse_time_offset(&format!("{}:00{}", &s[..2], &s[2..]))
+    } else {
+        parse_time_offset(s)
+    }
+}
+
+// The `iso8601` duration parser caps every component (`PT90M` comes out as zero) and ignores
+// trailing input, so durations are split into their designated components here instead. The
+// fraction, only allowed on seconds, is left to `fraction_nanosecond`.
+fn iso8601_duration(s: &str) -> Option<iso8601::Duration> {
+    let mut rest = s.strip_prefix('P')?;
+    if let Some(weeks) = rest.strip_suffix('W') {
+        if !weeks.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        return weeks.parse().ok().map(iso8601::Duration::Weeks);
+    }
+    // year, month, day, hour, minute, second
+    let mut fields = [0; 6];
+    let mut next_field = 0;
+    let mut in_time = false;
+    while !rest.is_empty() {
+        if !in_time && rest.starts_with('T') {
+            in_time = true;
+            next_field = 3;
+            rest = &rest[1..];
+            if rest.is_empty() {
+                return None;
+            }
+            continue;
+        }
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        let value = rest[..digits].parse().ok()?;
+        rest = &rest[digits..];
+        let has_fraction = rest.starts_with(['.', ',']);
+        if has_fraction {
+            rest = rest[1..].trim_start_matches(|c: char| c.is_ascii_digit());
+        }
+        let field = match (in_time, rest.bytes().next()?) {
+            (false, b'Y') => 0,
+            (false, b'M') => 1,
+            (false, b'D') => 2,
+            (true, b'H') => 3,
+            (true, b'M') => 4,
+            (true, b'S') => 5,
+            _ => return None,
+        };
+        if field < next_field || has_fraction && field != 5 {
+            return None;
+        }
+        fields[field] = value;
+        next_field = field + 1;
+        rest = &rest[1..];
+    }
+    if next_field == 0 {
+        return None;
+    }
+    let [year, month, day, hour, minute, second] = fields;
+    Some(iso8601::Duration::YMDHMS {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+        millisecond: 0,
+    })
+}
+
+fn parse_duration(s: &str) -> Result<::time::Duration, Iso8601SerdeError> {
+    let (negative, unsigned) = match s.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, s),
+    };
+    let duration = iso8601_duration(unsigned).ok_or(Iso8601SerdeError::Parse("duration"))?;
+    let duration = duration_from_iso8601(duration)?
+        + ::time::Duration::nanoseconds(fraction_nanosecond(unsigned).into());
+    Ok(if negative { -duration } else { duration })
+}
+
+// `PrimitiveDateTime::assume_offset` panics when the UTC instant falls outside the ±100_000 year
+// range, like `-100000-01-01T00:00:00+01:00`, which is an hour before the earliest UTC date.
+fn assume_offset_checked(
+    datetime: ::time::PrimitiveDateTime,
+    offset: ::time::UtcOffset,
+) -> Result<::time::OffsetDateTime, Iso8601SerdeError> {
+    let timestamp = datetime.assume_utc().unix_timestamp_nanos()
+        - i128::from(offset.as_seconds()) * 1_000_000_000;
+    match datetime_from_unix_timestamp_nanos(timestamp) {
+        Some(utc) => Ok(utc.to_offset(offset)),
+        None => Err(Iso8601SerdeError::OutOfRange {
+            name: "year in UTC",
+            minimum: -100_000,
+            maximum: 100_000,
+            value: if timestamp < 0 { -100_001 } else { 100_001 },
+        }),
+    }
+}
+
+// `time` panics on dates outside its ±100_000 year range instead of reporting an error.
+fn datetime_from_unix_timestamp_nanos(timestamp: i128) -> Option<::time::OffsetDateTime> {
+    let min = ::time::Date::try_from_ymd(-100_000, 1, 1).ok()?.midnight();
+    let max = ::time::Date::try_from_ymd(100_000, 12, 31)
+        .ok()?
+        .try_with_hms_nano(23, 59, 59, 999_999_999)
+        .ok()?;
+    let range = min.assume_utc().unix_timestamp_nanos()..=max.assume_utc().unix_timestamp_nanos();
+    if range.contains(&timestamp) {
+        Some(::time::OffsetDateTime::from_unix_timestamp_nanos(timestamp))
+    } else {
+        None
+    }
+}
+
+fn parse_utc_offset(s: &str) -> Result<::time::UtcOffset, Iso8601SerdeError> {
+    // The `iso8601` parser ignores trailing input, so only `Z`, `±HH`, `±HHMM` and `±HH:MM`,
+    // optionally followed by seconds, are let through to it.
+    let well_formed = s == "Z"
+        || s.starts_with(['+', '-'])
+            && match (s.len(), s.as_bytes().get(3)) {
+                (3, _) | (6, Some(b':')) | (9, Some(b':')) => true,
+                (5, Some(b)) | (7, Some(b)) => *b != b':',
+                _ => false,
+            };
+    if !well_formed {
+        return Err(Iso8601SerdeError::Parse("UTC offset"));
+    }
+    check_offset_digits(s)?;
+    let time = iso8601::time(&format!("00:00{}", s))
+        .map_err(|_| Iso8601SerdeError::Parse("UTC offset"))?;
+    let (_, offset) = time_from_iso8601_checked(time)?;
+    with_offset_seconds(offset, s)
+}
+
+fn time_offset_display(&(time, offset): &(::time::Time, ::time::UtcOffset)) -> TimeDisplay {
+    TimeDisplay(
+        time,
+        Some(OffsetDisplay {
+            offset,
+            zulu: false,
+        }),
+        Fraction::Lossless,
+    )
+}
+
+// An ISO 8601 interval, like `2020-01-01/2020-01-31`.
+struct IntervalDisplay<A, B>(A, B);
+
+impl<A: fmt::Display, B: fmt::Display> fmt::Display for IntervalDisplay<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.0, self.1)
+    }
+}
+
+fn split_interval(s: &str) -> Result<(&str, &str), Iso8601SerdeError> {
+    s.split_once('/')
+        .ok_or(Iso8601SerdeError::Parse("interval"))
+}
+
+fn parse_date_range(s: &str) -> Result<core::ops::Range<::time::Date>, Iso8601SerdeError> {
+    let (start, end) = split_interval(s)?;
+    let (start, end) = (parse_date(start)?, parse_date(end)?);
+    if end < start {
+        return Err(Iso8601SerdeError::ReversedInterval);
+    }
+    Ok(start..end)
+}
+
+// The end of an interval is either an instant or a duration from the start, like `P1D`.
+fn parse_interval(
+    s: &str,
+) -> Result<(::time::OffsetDateTime, ::time::OffsetDateTime), Iso8601SerdeError> {
+    let (start, end) = split_interval(s)?;
+    let start = parse_datetime(start)?;
+    let end = if end.starts_with('P') || end.starts_with("-P") {
+        let end = start.unix_timestamp_nanos() + parse_duration(end)?.whole_nanoseconds();
+        let local = end + i128::from(start.offset().as_seconds()) * 1_000_000_000;
+        datetime_from_unix_timestamp_nanos(local)
+            .and(datetime_from_unix_timestamp_nanos(end))
+            .ok_or(Iso8601SerdeError::OutOfRange {
+                name: "year of the interval end",
+                minimum: -100_000,
+                maximum: 100_000,
+                value: if end < 0 { -100_001 } else { 100_001 },
+            })?
+            .to_offset(start.offset())
+    } else {
+        parse_datetime(end)?
+    };
+    if end < start {
+        return Err(Iso8601SerdeError::ReversedInterval);
+    }
+    Ok((start, end))
+}
+
+/// The string [`date::serialize`] writes.
+pub fn date_to_string(date: ::time::Date) -> String {
+    DateDisplay(date_to_iso8601(date)).to_string()
+}
+
+/// The string [`datetime::serialize`] writes.
+pub fn datetime_to_string(datetime: ::time::OffsetDateTime) -> String {
+    DateTimeDisplay::from(datetime).to_string()
+}
+
+/// The string [`time_offset::serialize`] writes.
+pub fn time_offset_to_string(time: ::time::Time, offset: ::time::UtcOffset) -> String {
+    time_offset_display(&(time, offset)).to_string()
+}
+
+/// Like [`datetime_to_string`], but with exactly `N` fractional digits, truncating the rest:
+/// `format_datetime::<3>` writes milliseconds, `<6>` microseconds and `<0>` whole seconds. `N`
+/// above 9 doesn't compile.
+pub fn format_datetime<const N: usize>(datetime: ::time::OffsetDateTime) -> String {
+    DateTimeDisplay::from(datetime)
+        .fraction(FixedDigits::<N>::FRACTION)
+        .to_string()
+}
+
+/// Like [`time_offset_to_string`], but with exactly `N` fractional digits, as in
+/// [`format_datetime`].
+pub fn format_time_offset<const N: usize>(time: ::time::Time, offset: ::time::UtcOffset) -> String {
+    let TimeDisplay(time, offset, _) = time_offset_display(&(time, offset));
+    TimeDisplay(time, offset, FixedDigits::<N>::FRACTION).to_string()
+}
+
+fn serialize_via<T, S, V>(
+    value: &T,
+    serializer: S,
+    display: impl FnOnce(&T) -> V,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    V: fmt::Display,
+{
+    serializer.collect_str(&display(value))
+}
+
+fn serialize_optional_via<T, S, V>(
+    value: &Option<T>,
+    serializer: S,
+    display: impl FnOnce(&T) -> V,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    V: fmt::Display,
+{
+    match value {
+        Some(value) => serializer.serialize_some(&SerializeDisplay(display(value))),
+        None => serializer.serialize_none(),
+    }
+}
+
+// Inputs are echoed in deserialization errors, cut short so a huge string doesn't end up in logs.
+// Empty and whitespace-only inputs get the same message from every module, like `invalid value:
+// empty string, expected an ISO 8601 datetime`.
+fn unexpected_input(s: &str) -> Unexpected<'_> {
+    if s.trim().is_empty() {
+        return Unexpected::Other("empty string");
+    }
+    match s.char_indices().nth(64) {
+        Some((end, _)) => Unexpected::Str(&s[..end]),
+        None => Unexpected::Str(s),
+    }
+}
+
+// Malformed and out-of-range input alike is reported as serde's usual `invalid_value`, with the
+// reason it was rejected after what was expected.
+struct ExpectedIso8601(Iso8601SerdeError);
+
+impl serde::de::Expected for ExpectedIso8601 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Iso8601SerdeError::Parse(expected) => write!(f, "an ISO 8601 {}", expected),
+            err => write!(f, "a valid ISO 8601 value ({})", err),
+        }
+    }
+}
+
+fn parse_error<E: DeError>(s: &str, err: Iso8601SerdeError) -> E {
+    E::invalid_value(unexpected_input(s), &ExpectedIso8601(err))
+}
+
+// Parses straight from the deserializer's `&str`, so nothing is copied whether the input is
+// borrowed, transient or owned; serde forwards `visit_borrowed_str` and `visit_string` here.
+struct ParseVisitor<F>(F);
+
+impl<'de, T, F> Visitor<'de> for ParseVisitor<F>
+where
+    F: FnOnce(&str) -> Result<T, Iso8601SerdeError>,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an ISO 8601 string")
+    }
+
+    fn visit_str<E: DeError>(self, s: &str) -> Result<T, E> {
+        (self.0)(s).map_err(|err| parse_error(s, err))
+    }
+
+    fn visit_bytes<E: DeError>(self, bytes: &[u8]) -> Result<T, E> {
+        match core::str::from_utf8(bytes) {
+            Ok(s) => self.visit_str(s),
+            Err(_) => Err(E::invalid_value(Unexpected::Bytes(bytes), &self)),
+        }
+    }
+}
+
+struct OptionalParseVisitor<F>(F);
+
+impl<'de, T, F> Visitor<'de> for OptionalParseVisitor<F>
+where
+    F: FnOnce(&str) -> Result<T, Iso8601SerdeError>,
+{
+    type Value = Option<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an ISO 8601 string or null")
+    }
+
+    fn visit_none<E: DeError>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: DeError>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, d: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        d.deserialize_str(ParseVisitor(self.0)).map(Some)
+    }
+}
+
+// Parses one element of a sequence or field of a struct through `ParseVisitor`.
+struct ParseSeed<F>(F);
+
+impl<'de, T, F> DeserializeSeed<'de> for ParseSeed<F>
+where
+    F: FnOnce(&str) -> Result<T, Iso8601SerdeError>,
+{
+    type Value = T;
+
+    fn deserialize<D>(self, d: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_via(d, self.0)
+    }
+}
+
+fn deserialize_via<'de, T, D>(
+    d: D,
+    parse: impl FnOnce(&str) -> Result<T, Iso8601SerdeError>,
+) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    d.deserialize_str(ParseVisitor(parse))
+}
+
+fn deserialize_optional_via<'de, T, D>(
+    d: D,
+    parse: impl FnOnce(&str) -> Result<T, Iso8601SerdeError>,
+) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    d.deserialize_option(OptionalParseVisitor(parse))
+}
+
+struct SerializeDisplay<V>(V);
+
+impl<V: fmt::Display> Serialize for SerializeDisplay<V> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&self.0)
+    }
+}
+
+fn serialize_seq_via<T, S, V>(
+    values: &[T],
+    serializer: S,
+    display: impl Fn(&T) -> V,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    V: fmt::Display,
+{
+    serializer.collect_seq(values.iter().map(|value| SerializeDisplay(display(value))))
+}
+
+struct SeqVisitor<F>(F);
+
+impl<'de, T, F> Visitor<'de> for SeqVisitor<F>
+where
+    F: Fn(&str) -> Result<T, Iso8601SerdeError>,
+{
+    type Value = Vec<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a sequence of ISO 8601 strings")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+        while let Some(value) = seq.next_element_seed(ParseSeed(&self.0))? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+fn deserialize_seq_via<'de, T, D>(
+    d: D,
+    parse: impl Fn(&str) -> Result<T, Iso8601SerdeError>,
+) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    d.deserialize_seq(SeqVisitor(parse))
+}
+
+// The `(unix_timestamp_nanos, offset_seconds)` tuple the `datetime` modules write in formats that
+// aren't human-readable.
+struct CompactDatetime(::time::OffsetDateTime);
+
+impl Serialize for CompactDatetime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        (self.0.unix_timestamp_nanos(), self.0.offset().as_seconds()).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CompactDatetime {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (timestamp, offset) = <(i128, i32)>::deserialize(d)?;
+        let time = datetime_from_unix_timestamp_nanos(timestamp).ok_or_else(|| {
+            DeError::invalid_value(
+                Unexpected::Other(&format!("integer `{}`", timestamp)),
+                &"a Unix timestamp in nanoseconds within the years -100000..=100000",
+            )
+        })?;
+        let offset = ::time::UtcOffset::seconds(offset);
+        offset_in_range(offset).map_err(DeError::custom)?;
+        Ok(CompactDatetime(time.to_offset(offset)))
+    }
+}
+
+fn serialize_datetime_via<S, V>(
+    time: &::time::OffsetDateTime,
+    serializer: S,
+    display: impl FnOnce(&::time::OffsetDateTime) -> V,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    V: fmt::Display,
+{
+    datetime_in_range(*time).map_err(SerError::custom)?;
+    if !serializer.is_human_readable() {
+        return CompactDatetime(*time).serialize(serializer);
+    }
+    serialize_via(time, serializer, display)
+}
+
+fn deserialize_datetime_via<'de, D>(
+    d: D,
+    parse: impl FnOnce(&str) -> Result<::time::OffsetDateTime, Iso8601SerdeError>,
+) -> Result<::time::OffsetDateTime, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if !d.is_human_readable() {
+        return CompactDatetime::deserialize(d).map(|CompactDatetime(time)| time);
+    }
+    deserialize_via(d, parse)
+}
+
+fn serialize_with_fraction<S>(
+    time: &::time::OffsetDateTime,
+    serializer: S,
+    fraction: Fraction,
+    zulu: bool,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serialize_datetime_via(time, serializer, |time| {
+        let display = DateTimeDisplay::from(*time).fraction(fraction);
+        if zulu {
+            display.zulu()
+        } else {
+            display
+        }
+    })
+}
+
+// Fills in an `optional` module for `Option<OffsetDateTime>` from its parent's `serialize` and
+// `deserialize`, with `None` as null.
+macro_rules! optional_datetime {
+    () => {
+        struct Value(::time::OffsetDateTime);
+
+        impl Serialize for Value {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                super::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for Value {
+            fn deserialize<D>(d: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                super::deserialize(d).map(Value)
+            }
+        }
+
+        pub fn serialize<S>(
+            time: &Option<::time::OffsetDateTime>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match time {
+                Some(time) => serializer.serialize_some(&Value(*time)),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D>(d: D) -> Result<Option<::time::OffsetDateTime>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            Ok(Option::<Value>::deserialize(d)?.map(|Value(time)| time))
+        }
+    };
+}
+
+/// Datetimes are written as ISO 8601 strings in human-readable formats like JSON. In other
+/// formats, like bincode, they're written as `(unix_timestamp_nanos, offset_seconds)`, an
+/// `(i128, i32)` tuple that keeps both the instant and the offset exactly. This holds for every
+/// module here that writes or reads a single datetime as a string, and for `optional` and `vec`.
+pub mod datetime {
+    use super::*;
+
+    pub fn serialize<S>(time: &::time::OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_datetime_via(time, serializer, |time| DateTimeDisplay::from(*time))
+    }
+
+    pub fn deserialize<'de, D>(d: D) -> Result<::time::OffsetDateTime, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_datetime_via(d, parse_datetime)
+    }
+
+    /// Like [`serialize`], but writes the instant in `offset`. Meant to be wrapped in a function
+    /// for `#[serde(serialize_with = "...")]`.
+    pub fn serialize_in_offset<S>(
+        time: &::time::OffsetDateTime,
+        offset: ::time::UtcOffset,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize(&time.to_offset(offset), serializer)
+    }
+
+    /// A datetime to be written in an offset only known at runtime, like one taken from a request
+    /// header, which a `serialize_with` function can't be given. Serializes with
+    /// [`serialize_in_offset`]; the borrowed datetime is left as it is.
+    #[derive(Clone, Copy, Debug)]
+    pub struct InOffset<'a>(pub &'a ::time::OffsetDateTime, pub ::time::UtcOffset);
+
+    impl Serialize for InOffset<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serialize_in_offset(self.0, self.1, serializer)
+        }
+    }
+
+    pub mod optional {
+        use super::*;
+
+        optional_datetime!();
+    }
+
+    /// Same as [`optional`], but an empty string is read as `None` too, as CSV conversions tend
+    /// to write it. Any other invalid string is still an error, and `None` is written as null.
+    pub mod empty_as_none {
+        use super::*;
+
+        pub use super::optional::serialize;
+
+        pub fn deserialize<'de, D>(d: D) -> Result<Option<::time::OffsetDateTime>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            if !d.is_human_readable() {
+                return optional::deserialize(d);
+            }
+            let time = deserialize_optional_via(d, |s| match s {
+                "" => Ok(None),
+                s => parse_datetime(s).map(Some),
+            })?;
+            Ok(time.flatten())
+        }
+    }
+
+    /// `Option<Option<OffsetDateTime>>` for telling a missing field apart from null, as in JSON
+    /// Merge Patch: the outer `None` is a missing field and the inner `None` is null. Use with
+    /// `#[serde(default, skip_serializing_if = "Option::is_none", with = "...")]` so that a
+    /// missing field deserializes to `None` and `None` isn't written.
+    pub mod double_option {
+        use super::*;
+
+        pub fn serialize<S>(
+            time: &Option<Option<::time::OffsetDateTime>>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match time {
+                Some(time) => optional::serialize(time, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D>(d: D) -> Result<Option<Option<::time::OffsetDateTime>>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            optional::deserialize(d).map(Some)
+        }
+    }
+
+    /// `Vec<OffsetDateTime>` as a sequence of ISO 8601 strings.
+    pub mod vec {
+        use super::*;
+
+        pub fn serialize<S>(
+            times: &[::time::OffsetDateTime],
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            for time in times {
+                datetime_in_range(*time).map_err(SerError::custom)?;
+            }
+            if !serializer.is_human_readable() {
+                return serializer.collect_seq(times.iter().map(|time| CompactDatetime(*time)));
+            }
+            serialize_seq_via(times, serializer, |time| DateTimeDisplay::from(*time))
+        }
+
+        pub fn deserialize<'de, D>(d: D) -> Result<Vec<::time::OffsetDateTime>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            if !d.is_human_readable() {
+                let times = Vec::<CompactDatetime>::deserialize(d)?;
+                return Ok(times
+                    .into_iter()
+                    .map(|CompactDatetime(time)| time)
+                    .collect());
+            }
+            deserialize_seq_via(d, parse_datetime)
+        }
+    }
+
+    /// Same as the parent module, but writes a zero offset as `Z` instead of `+00:00`.
+    pub mod zulu {
+        use super::*;
+
+        pub fn serialize<S>(time: &::time::OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serialize_datetime_via(time, serializer, |time| DateTimeDisplay::from(*time).zulu())
+        }
+
+        pub use super::deserialize;
+    }
+
+    /// Same as the parent module, but fractional seconds are truncated on serialize, rounding
+    /// towards the past, so `12:00:00.999` is written as `12:00:00` with no fraction. Fractional
+    /// input is still accepted on deserialize.
+    pub mod seconds_precision {
+        use super::*;
+
+        pub fn serialize<S>(time: &::time::OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serialize_with_fraction(time, serializer, FixedDigits::<0>::FRACTION, false)
+        }
+
+        pub use super::deserialize;
+    }
+
+    /// Same as the parent module, but always writes exactly three fractional digits, truncating
+    /// anything below a millisecond, like `12:00:00.000` and `12:00:00.123`.
+    pub mod fixed_millis {
+        use super::*;
+
+        pub fn serialize<S>(time: &::time::OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serialize_with_fraction(time, serializer, FixedDigits::<3>::FRACTION, false)
+        }
+
+        pub use super::deserialize;
+    }
+
+    /// Same as the parent module, but always writes exactly six fractional digits, truncating
+    /// anything below a microsecond, like `12:00:00.000000` and `12:00:00.123456`.
+    pub mod fixed_micros {
+        use super::*;
+
+        pub fn serialize<S>(time: &::time::OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serialize_with_fraction(time, serializer, FixedDigits::<6>::FRACTION, false)
+        }
+
+        pub use super::deserialize;
+    }
+
+    /// Same as the parent module, but writes only the significant fractional digits, and none for
+    /// whole seconds, like `12:00:00`, `12:00:00.5` and `12:00:00.123456`.
+    pub mod trimmed {
+        use super::*;
+
+        pub fn serialize<S>(time: &::time::OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serialize_with_fraction(time, serializer, Fraction::Trimmed, false)
+        }
+
+        pub use super::deserialize;
+    }
+
+    /// Converts to UTC both ways: deserialized values keep their instant but have a zero offset,
+    /// and values are written with `Z`.
+    pub mod utc_normalized {
+        use super::*;
+
+        pub fn serialize<S>(time: &::time::OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            zulu::serialize(&time.to_offset(::time::UtcOffset::UTC), serializer)
+        }
+
+        pub fn deserialize<'de, D>(d: D) -> Result<::time::OffsetDateTime, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            super::deserialize(d).map(|time| time.to_offset(::time::UtcOffset::UTC))
+        }
+    }
+
+    /// Same as [`utc_normalized`], but always with nine fractional digits, so every string has the
+    /// same width and sorting them as strings sorts them chronologically, whatever their original
+    /// offsets. Years outside 0..=9999 would be written with a sign and break the ordering, so
+    /// they're rejected on serialize.
+    pub mod sortable {
+        use super::*;
+
+        pub fn serialize<S>(time: &::time::OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let time = time.to_offset(::time::UtcOffset::UTC);
+            if !(0..=9999).contains(&time.year()) {
+                return Err(SerError::custom(Iso8601SerdeError::OutOfRange {
+                    name: "year",
+                    minimum: 0,
+                    maximum: 9999,
+                    value: time.year().into(),
+                }));
+            }
+            serialize_with_fraction(&time, serializer, FixedDigits::<9>::FRACTION, true)
+        }
+
+        pub use super::utc_normalized::deserialize;
+    }
+
+    /// A struct of three ISO 8601 strings instead of one, like
+    /// `{ "date": "2020-01-01", "time": "00:00:00.000", "offset": "+00:00" }`, read back as the
+    /// wall-clock time in that offset.
+    pub mod structured {
+        use super::*;
+
+        use serde::de::MapAccess;
+        use serde::ser::SerializeStruct;
+
+        const FIELDS: &[&str] = &["date", "time", "offset"];
+
+        pub fn serialize<S>(time: &::time::OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            datetime_in_range(*time).map_err(SerError::custom)?;
+            let mut state = serializer.serialize_struct("DateTime", 3)?;
+            state.serialize_field(
+                "date",
+                &SerializeDisplay(DateDisplay(date_to_iso8601(time.date()))),
+            )?;
+            state.serialize_field(
+                "time",
+                &SerializeDisplay(TimeDisplay(time.time(), None, Fraction::Lossless)),
+            )?;
+            state.serialize_field(
+                "offset",
+                &SerializeDisplay(OffsetDisplay {
+                    offset: time.offset(),
+                    zulu: false,
+                }),
+            )?;
+            state.end()
+        }
+
+        pub fn deserialize<'de, D>(d: D) -> Result<::time::OffsetDateTime, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            d.deserialize_struct("DateTime", FIELDS, StructuredVisitor)
+        }
+
+        // The offset has its own field, so one in the time would be ambiguous.
+        fn parse_time(s: &str) -> Result<::time::Time, Iso8601SerdeError> {
+            if s.contains(['Z', '+', '-']) {
+                return Err(Iso8601SerdeError::Parse("time without an offset"));
+            }
+            parse_time_offset(s).map(|(time, _)| time)
+        }
+
+        struct StructuredVisitor;
+
+        impl<'de> Visitor<'de> for StructuredVisitor {
+            type Value = ::time::OffsetDateTime;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a struct with ISO 8601 date, time and offset fields")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let date = seq
+                    .next_element_seed(ParseSeed(parse_date))?
+                    .ok_or_else(|| DeError::invalid_length(0, &self))?;
+                let time = seq
+                    .next_element_seed(ParseSeed(parse_time))?
+                    .ok_or_else(|| DeError::invalid_length(1, &self))?;
+                let offset = seq
+                    .next_element_seed(ParseSeed(parse_utc_offset))?
+                    .ok_or_else(|| DeError::invalid_length(2, &self))?;
+                assume_offset_checked(::time::PrimitiveDateTime::new(date, time), offset)
+                    .map_err(DeError::custom)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let (mut date, mut time, mut offset) = (None, None, None);
+                while let Some(key) = map.next_key::<Cow<'_, str>>()? {
+                    match key.as_ref() {
+                        "date" if date.is_none() => {
+                            date = Some(map.next_value_seed(ParseSeed(parse_date))?)
+                        }
+                        "time" if time.is_none() => {
+                            time = Some(map.next_value_seed(ParseSeed(parse_time))?)
+                        }
+                        "offset" if offset.is_none() => {
+                            offset = Some(map.next_value_seed(ParseSeed(parse_utc_offset))?)
+                        }
+                        "date" => return Err(DeError::duplicate_field("date")),
+                        "time" => return Err(DeError::duplicate_field("time")),
+                        "offset" => return Err(DeError::duplicate_field("offset")),
+                        key => return Err(DeError::unknown_field(key, FIELDS)),
+                    }
+                }
+                let date = date.ok_or_else(|| DeError::missing_field("date"))?;
+                let time = time.ok_or_else(|| DeError::missing_field("time"))?;
+                let offset = offset.ok_or_else(|| DeError::missing_field("offset"))?;
+                assume_offset_checked(::time::PrimitiveDateTime::new(date, time), offset)
+                    .map_err(DeError::custom)
+            }
+        }
+    }
+
+    /// Unix timestamps in whole seconds. Sub-second precision is dropped on serialize, rounding
+    /// towards the past, and deserialized values are always in UTC.
+    pub mod unix_timestamp {
+        use super::*;
+
+        pub fn serialize<S>(time: &::time::OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_i64(time.unix_timestamp())
+        }
+
+        pub fn deserialize<'de, D>(d: D) -> Result<::time::OffsetDateTime, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let timestamp = i64::deserialize(d)?;
+            datetime_from_unix_timestamp_nanos(i128::from(timestamp) * 1_000_000_000).ok_or_else(
+                || {
+                    DeError::invalid_value(
+                        Unexpected::Signed(timestamp),
+                        &"a Unix timestamp within the years -100000..=100000",
+                    )
+                },
+            )
+        }
+
+        pub mod optional {
+            use super::*;
+
+            optional_datetime!();
+        }
+    }
+
+    /// Unix timestamps in whole milliseconds, as used by JavaScript's `Date.now()`. Sub-millisecond
+    /// precision is dropped on serialize, rounding towards the past, and deserialized values are
+    /// always in UTC.
+    pub mod unix_timestamp_millis {
+        use super::*;
+
+        pub fn serialize<S>(time: &::time::OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            // `unix_timestamp` rounds towards the past and `millisecond` is always positive, so
+            // this holds before 1970 as well.
+            serializer.serialize_i64(time.unix_timestamp() * 1_000 + i64::from(time.millisecond()))
+        }
+
+        pub fn deserialize<'de, D>(d: D) -> Result<::time::OffsetDateTime, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let timestamp = i64::deserialize(d)?;
+            datetime_from_unix_timestamp_nanos(i128::from(timestamp) * 1_000_000).ok_or_else(|| {
+                DeError::invalid_value(
+                    Unexpected::Signed(timestamp),
+                    &"a Unix timestamp in milliseconds within the years -100000..=100000",
+                )
+            })
+        }
+
+        pub mod optional {
+            use super::*;
+
+            optional_datetime!();
+        }
+    }
+
+    /// Unix timestamps in nanoseconds as an `i128`, keeping full precision. Deserialized values
+    /// are always in UTC.
+    pub mod unix_timestamp_nanos {
+        use super::*;
+
+        pub fn serialize<S>(time: &::time::OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_i128(time.unix_timestamp_nanos())
+        }
+
+        pub fn deserialize<'de, D>(d: D) -> Result<::time::OffsetDateTime, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let timestamp = i128::deserialize(d)?;
+            datetime_from_unix_timestamp_nanos(timestamp).ok_or_else(|| {
+                DeError::invalid_value(
+                    Unexpected::Other(&format!("integer `{}`", timestamp)),
+                    &"a Unix timestamp in nanoseconds within the years -100000..=100000",
+                )
+            })
+        }
+
+        pub mod optional {
+            use super::*;
+
+            optional_datetime!();
+        }
+    }
+
+    /// `[seconds, nanoseconds]` since the Unix epoch, losslessly and in UTC, like protobuf's
+    /// `Timestamp`. The seconds are floored, so the nanoseconds are always in
+    /// `0..1_000_000_000`, and deserializing rejects anything else.
+    pub mod unix_tuple {
+        use super::*;
+
+        use serde::ser::SerializeTuple;
+
+        pub fn serialize<S>(time: &::time::OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let nanos = time.unix_timestamp_nanos();
+            let mut tuple = serializer.serialize_tuple(2)?;
+            tuple.serialize_element(&(nanos.div_euclid(1_000_000_000) as i64))?;
+            tuple.serialize_element(&(nanos.rem_euclid(1_000_000_000) as u32))?;
+            tuple.end()
+        }
+
+        pub fn deserialize<'de, D>(d: D) -> Result<::time::OffsetDateTime, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            d.deserialize_tuple(2, UnixTupleVisitor)
+        }
+
+        struct UnixTupleVisitor;
+
+        impl<'de> Visitor<'de> for UnixTupleVisitor {
+            type Value = ::time::OffsetDateTime;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a [seconds, nanoseconds] sequence")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let seconds: i64 = seq
+                    .next_element()?
+                    .ok_or_else(|| DeError::invalid_length(0, &self))?;
+                let nanos: u32 = seq
+                    .next_element()?
+                    .ok_or_else(|| DeError::invalid_length(1, &self))?;
+                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(DeError::invalid_length(3, &self));
+                }
+                if nanos >= 1_000_000_000 {
+                    return Err(DeError::invalid_value(
+                        Unexpected::Unsigned(u64::from(nanos)),
+                        &"nanoseconds in 0..1_000_000_000",
+                    ));
+                }
+                datetime_from_unix_timestamp_nanos(
+                    i128::from(seconds) * 1_000_000_000 + i128::from(nanos),
+                )
+                .ok_or_else(|| {
+                    DeError::invalid_value(
+                        Unexpected::Signed(seconds),
+                        &"a Unix timestamp within the years -100000..=100000",
+                    )
+                })
+            }
+        }
+    }
+
+    // Accepts an ISO 8601 string or a Unix timestamp in seconds, and with `float` also one with a
+    // fractional part.
+    struct FlexibleVisitor {
+        float: bool,
+    }
+
+    impl<'de> Visitor<'de> for FlexibleVisitor {
+        type Value = ::time::OffsetDateTime;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("an ISO 8601 datetime or a Unix timestamp")
+        }
+
+        fn visit_i64<E: DeError>(self, timestamp: i64) -> Result<Self::Value, E> {
+            datetime_from_unix_timestamp_nanos(i128::from(timestamp) * 1_000_000_000).ok_or_else(
+                || {
+                    E::invalid_value(
+                        Unexpected::Signed(timestamp),
+                        &"a Unix timestamp within the years -100000..=100000",
+                    )
+                },
+            )
+        }
+
+        fn visit_u64<E: DeError>(self, timestamp: u64) -> Result<Self::Value, E> {
+            match i64::try_from(timestamp) {
+                Ok(timestamp) => self.visit_i64(timestamp),
+                Err(_) => Err(E::invalid_value(
+                    Unexpected::Unsigned(timestamp),
+                    &"a Unix timestamp within the years -100000..=100000",
+                )),
+            }
+        }
+
+        fn visit_f64<E: DeError>(self, timestamp: f64) -> Result<Self::Value, E> {
+            let invalid = || {
+                E::invalid_value(
+                    Unexpected::Float(timestamp),
+                    &"a Unix timestamp within the years -100000..=100000",
+                )
+            };
+            if !self.float {
+                return Err(E::invalid_type(Unexpected::Float(timestamp), &self));
+            }
+            // Far beyond `time`'s range, but small enough to convert to an integer exactly.
+            if !timestamp.is_finite() || timestamp.abs() > 1e15 {
+                return Err(invalid());
+            }
+            // `floor` and `round` aren't in `core`, so the casts do the rounding.
+            let mut seconds = timestamp as i64;
+            if (seconds as f64) > timestamp {
+                seconds -= 1;
+            }
+            let nanoseconds = ((timestamp - seconds as f64) * 1e9 + 0.5) as i64;
+            datetime_from_unix_timestamp_nanos(
+                i128::from(seconds) * 1_000_000_000 + i128::from(nanoseconds),
+            )
+            .ok_or_else(invalid)
+        }
+
+        fn visit_str<E: DeError>(self, s: &str) -> Result<Self::Value, E> {
+            parse_datetime(s).map_err(|err| parse_error(s, err))
+        }
+    }
+
+    /// Same as the parent module, but a Unix timestamp in whole seconds is also accepted on
+    /// deserialize, as in [`unix_timestamp`]. ISO 8601 strings are written on serialize.
+    pub mod flexible {
+        use super::*;
+
+        pub use super::serialize;
+
+        pub fn deserialize<'de, D>(d: D) -> Result<::time::OffsetDateTime, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            if !d.is_human_readable() {
+                return super::deserialize(d);
+            }
+            d.deserialize_any(FlexibleVisitor { float: false })
+        }
+    }
+
+    /// Same as [`flexible`], but a Unix timestamp in seconds may also have a fractional part, like
+    /// `1577836800.5`, kept to the nanosecond. This relies on `deserialize_any`, so in formats
+    /// that aren't self-describing, like bincode, the parent module's encoding is read instead.
+    pub mod any {
+        use super::*;
+
+        pub use super::serialize;
+
+        pub fn deserialize<'de, D>(d: D) -> Result<::time::OffsetDateTime, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            if !d.is_human_readable() {
+                return super::deserialize(d);
+            }
+            d.deserialize_any(FlexibleVisitor { float: true })
+        }
+    }
+
+    /// Same as the parent module, but a bare date like `2020-01-01` is also accepted on
+    /// deserialize, as midnight UTC, and so are common deviations from ISO 8601: missing leading
+    /// zeros like `2020-1-1T9:05:00Z`, a lowercase `t` or `z`, and a space instead of the `T`. A
+    /// datetime without an offset is read as UTC, as in [`assume_utc`].
+    pub mod lenient {
+        use super::*;
+
+        pub use super::serialize;
+
+        pub fn deserialize<'de, D>(d: D) -> Result<::time::OffsetDateTime, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserialize_datetime_via(d, parse_lenient)
+        }
+
+        fn parse_lenient(s: &str) -> Result<::time::OffsetDateTime, Iso8601SerdeError> {
+            let err = match parse_datetime_assuming_utc(s) {
+                Ok(time) => return Ok(time),
+                Err(err) => err,
+            };
+            let s = normalize(s).map_or(Cow::Borrowed(s), Cow::Owned);
+            if s.contains('T') {
+                return parse_datetime_assuming_utc(&s).map_err(
+                    |normalized_err| match normalized_err {
+                        Iso8601SerdeError::Parse(_) => err,
+                        normalized_err => normalized_err,
+                    },
+                );
+            }
+            match parse_date(&s) {
+                Err(Iso8601SerdeError::Parse(_)) => Err(err),
+                date => date.map(|date| date.midnight().assume_utc()),
+            }
+        }
+
+        // Rewrites `Y-M-D[ H:M[:S][.f][zone]]` into its extended ISO 8601 form, or gives up on
+        // any other shape. Only whole ASCII characters are split off, so no slice can panic.
+        fn normalize(s: &str) -> Option<String> {
+            let (date, time) = match s.find(['T', 't', ' ']) {
+                Some(separator) => (&s[..separator], Some(&s[separator + 1..])),
+                None => (s, None),
+            };
+            let mut parts = date.split('-');
+            let (year, month, day) = (parts.next()?, parts.next()?, parts.next()?);
+            if parts.next().is_some() || !is_digits(year, 4) {
+                return None;
+            }
+            let mut normalized = format!("{}-{}-{}", year, pad(month)?, pad(day)?);
+            if let Some(time) = time {
+                let (time, zone) =
+                    time.split_at(time.find(['Z', 'z', '+', '-']).unwrap_or(time.len()));
+                let (time, fraction) = time.split_at(time.find(['.', ',']).unwrap_or(time.len()));
+                normalized.push('T');
+                for (index, part) in time.split(':').enumerate() {
+                    match index {
+                        0 => {}
+                        1 | 2 => normalized.push(':'),
+                        _ => return None,
+                    }
+                    normalized.push_str(&pad(part)?);
+                }
+                normalized.push_str(fraction);
+                normalized.push_str(&zone.to_ascii_uppercase());
+            }
+            Some(normalized)
+        }
+
+        fn pad(part: &str) -> Option<String> {
+            match part.len() {
+                1 | 2 if part.bytes().all(|b| b.is_ascii_digit()) => Some(format!("{:0>2}", part)),
+                _ => None,
+            }
+        }
+    }
+
+    /// Same as the parent module, but leading and trailing whitespace is ignored on deserialize,
+    /// like in `" 2020-01-01T00:00:00Z "`.
+    pub mod trim_whitespace {
+        use super::*;
+
+        pub use super::serialize;
+
+        pub fn deserialize<'de, D>(d: D) -> Result<::time::OffsetDateTime, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserialize_datetime_via(d, |s| parse_datetime(s.trim()))
+        }
+    }
+
+    /// Same as [`lenient`], but midnight UTC is written as a bare date like `2020-01-01`, as for
+    /// iCalendar all-day events. Other times, and midnight in any other offset, are written in
+    /// full.
+    pub mod date_if_midnight {
+        use super::*;
+
+        pub fn serialize<S>(time: &::time::OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            if time.time() == ::time::Time::midnight() && time.offset() == ::time::UtcOffset::UTC {
+                return serialize_datetime_via(time, serializer, |time| {
+                    DateDisplay(date_to_iso8601(time.date()))
+                });
+            }
+            super::serialize(time, serializer)
+        }
+
+        pub use super::lenient::deserialize;
+    }
+
+    /// Same as the parent module, but leniently carries offset minutes above 59 into the hours on
+    /// deserialize, for producers that write `+00:90` meaning `+01:30`. The result still has to
+    /// be within ±14:00.
+    pub mod normalize_offset {
+        use super::*;
+
+        pub use super::serialize;
+
+        pub fn deserialize<'de, D>(d: D) -> Result<::time::OffsetDateTime, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserialize_datetime_via(d, |s| match carry_offset_minutes(s) {
+                Some(s) => parse_datetime(&s),
+                None => parse_datetime(s),
+            })
+        }
+
+        fn carry_offset_minutes(s: &str) -> Option<String> {
+            let t = s.find('T')?;
+            let (sign, hours, minutes, seconds) = offset_digits(&s[t..])?;
+            let (hours, minutes): (u32, u32) = (hours.parse().ok()?, minutes?.parse().ok()?);
+            if minutes < 60 {
+                return None;
+            }
+            let sign = t + sign;
+            Some(format!(
+                "{}{}{:02}:{:02}{}{}",
+                &s[..sign],
+                &s[sign..sign + 1],
+                hours + minutes / 60,
+                minutes % 60,
+                if seconds.is_some() { ":" } else { "" },
+                seconds.unwrap_or_default()
+            ))
+        }
+    }
+
+    /// Same as the parent module, but a single space is also accepted between the date and the
+    /// time on deserialize, as in `2020-01-01 12:00:00+00`.
+    pub mod lenient_separator {
+        use super::*;
+
+        pub use super::serialize;
+
+        pub fn deserialize<'de, D>(d: D) -> Result<::time::OffsetDateTime, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserialize_datetime_via(d, |s| {
+                if !s.contains('T') && s.matches(' ').count() == 1 {
+                    parse_datetime(&s.replacen(' ', "T", 1))
+                } else {
+                    parse_datetime(s)
+                }
+            })
+        }
+    }
+
+    /// Same as the parent module, but only the RFC 3339 profile of ISO 8601 is accepted on
+    /// deserialize, like `2020-01-01T00:00:00.5+09:00`: week and ordinal dates, basic forms,
+    /// missing seconds and missing offsets are rejected.
+    pub mod rfc3339_strict {
+        use super::*;
+
+        pub use super::serialize;
+
+        pub fn deserialize<'de, D>(d: D) -> Result<::time::OffsetDateTime, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserialize_datetime_via(d, |s| {
+                if !is_rfc3339(s) {
+                    return Err(Iso8601SerdeError::Parse("datetime in the RFC 3339 profile"));
+                }
+                parse_datetime(s)
+            })
+        }
+    }
+
+    /// Same as the parent module, but a datetime without a zone designator, like
+    /// `2020-01-01T12:00:00`, is read as UTC instead of being rejected. It is always UTC, never
+    /// the system's local offset.
+    pub mod assume_utc {
+        use super::*;
+
+        pub use super::serialize;
+
+        pub fn deserialize<'de, D>(d: D) -> Result<::time::OffsetDateTime, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserialize_datetime_via(d, parse_datetime_assuming_utc)
+        }
+    }
+}
+
+pub mod date {
+    use super::*;
+
+    pub fn serialize<S>(time: &::time::Date, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_via(time, serializer, |time| DateDisplay(date_to_iso8601(*time)))
+    }
+
+    pub fn deserialize<'de, D>(d: D) -> Result<::time::Date, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_via(d, parse_date)
+    }
+
+    pub mod optional {
+        use super::*;
+
+        pub fn serialize<S>(time: &Option<::time::Date>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serialize_optional_via(time, serializer, |time| DateDisplay(date_to_iso8601(*time)))
+        }
+
+        pub fn deserialize<'de, D>(d: D) -> Result<Option<::time::Date>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserialize_optional_via(d, parse_date)
+        }
+    }
+
+    /// Same as [`optional`], but the all-zero sentinel `0000-00-00` that MySQL and others write
+    /// for a missing date, also as `0000-00-00T00:00:00` or MySQL's `0000-00-00 00:00:00`, is
+    /// read as `None` too. `None` is written as null.
+    pub mod nullable_sentinel {
+        use super::*;
+
+        pub use super::optional::serialize;
+
+        pub fn deserialize<'de, D>(d: D) -> Result<Option<::time::Date>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let date = deserialize_optional_via(d, |s| match s {
+                "0000-00-00" | "0000-00-00T00:00:00" | "0000-00-00 00:00:00" => Ok(None),
+                s => parse_date(s).map(Some),
+            })?;
+            Ok(date.flatten())
+        }
+    }
+
+    /// Same as the parent module, but a full datetime is also accepted on deserialize, keeping
+    /// only its date as written, in its own offset: `2020-01-01T23:00:00-05:00` is 2020-01-01.
+    /// The time and offset are still validated. Dates are written on serialize.
+    pub mod from_datetime_or_date {
+        use super::*;
+
+        pub use super::serialize;
+
+        pub fn deserialize<'de, D>(d: D) -> Result<::time::Date, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserialize_via(d, |s| {
+                if s.contains('T') {
+                    parse_datetime(s).map(|time| time.date())
+                } else {
+                    parse_date(s)
+                }
+            })
+        }
+    }
+
+    /// Same as the parent module, but the reduced-precision forms `2020` and `2020-01` are also
+    /// accepted on deserialize, as the first day of that year or month. The basic `202001` is
+    /// still rejected, as ISO 8601 does to keep it apart from `YYMMDD`. Full dates are written on
+    /// serialize.
+    pub mod reduced {
+        use super::*;
+
+        pub use super::serialize;
+
+        pub fn deserialize<'de, D>(d: D) -> Result<::time::Date, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserialize_via(d, |s| {
+                let (year, month) = match s.len() {
+                    _ if !s.is_ascii() => return parse_date(s),
+                    4 if is_digits(s, 4) => (s, "01"),
+                    7 if is_digits(&s[..4], 4) && &s[4..5] == "-" && is_digits(&s[5..], 2) => {
+                        (&s[..4], &s[5..])
+                    }
+                    _ => return parse_date(s),
+                };
+                let year = year.parse().unwrap_or_default();
+                let month = month.parse().unwrap_or_default();
+                component_range(::time::Date::try_from_ymd(year, month, 1))
+            })
+        }
+    }
+
+    /// The number of days since 1970-01-01 as an `i32`, negative before it, like `18262` for
+    /// 2020-01-01, as used by columnar formats like Arrow and Parquet.
+    pub mod epoch_days {
+        use super::*;
+
+        // The Julian day of 1970-01-01.
+        const EPOCH: i64 = 2_440_588;
+
+        pub fn serialize<S>(date: &::time::Date, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            // Dates are within ±100_000 years, so the days always fit.
+            serializer.serialize_i32((date.julian_day() - EPOCH) as i32)
+        }
+
+        pub fn deserialize<'de, D>(d: D) -> Result<::time::Date, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let days = i32::deserialize(d)?;
+            // `Date::from_julian_day` panics outside the ±100_000 year range.
+            let min = ::time::Date::try_from_ymd(-100_000, 1, 1).map_err(DeError::custom)?;
+            let max = ::time::Date::try_from_ymd(100_000, 12, 31).map_err(DeError::custom)?;
+            let julian_day = EPOCH + i64::from(days);
+            if !(min.julian_day()..=max.julian_day()).contains(&julian_day) {
+                return Err(DeError::invalid_value(
+                    Unexpected::Signed(days.into()),
+                    &"a number of days since 1970-01-01 within the years -100000..=100000",
+                ));
+            }
+            Ok(::time::Date::from_julian_day(julian_day))
+        }
+    }
+
+    /// Calendar dates as a `[year, month, day]` sequence of numbers, like `[2020, 1, 1]`, for
+    /// protocols that avoid parsing strings.
+    pub mod ymd_array {
+        use super::*;
+
+        use serde::ser::SerializeTuple;
+
+        pub fn serialize<S>(date: &::time::Date, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let (year, month, day) = date.as_ymd();
+            let mut tuple = serializer.serialize_tuple(3)?;
+            tuple.serialize_element(&year)?;
+            tuple.serialize_element(&month)?;
+            tuple.serialize_element(&day)?;
+            tuple.end()
+        }
+
+        pub fn deserialize<'de, D>(d: D) -> Result<::time::Date, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            d.deserialize_tuple(3, YmdVisitor)
+        }
+
+        struct YmdVisitor;
+
+        impl<'de> Visitor<'de> for YmdVisitor {
+            type Value = ::time::Date;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a [year, month, day] sequence")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let year = seq
+                    .next_element()?
+                    .ok_or_else(|| DeError::invalid_length(0, &self))?;
+                let month: u8 = seq
+                    .next_element()?
+                    .ok_or_else(|| DeError::invalid_length(1, &self))?;
+                let day: u8 = seq
+                    .next_element()?
+                    .ok_or_else(|| DeError::invalid_length(2, &self))?;
+                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(DeError::invalid_length(4, &self));
+                }
+                date_from_iso8601(iso8601::Date::YMD {
+                    year,
+                    month: u32::from(month),
+                    day: u32::from(day),
+                })
+                .map_err(DeError::custom)
+            }
+        }
+    }
+
+    /// Same as the parent module, but leading and trailing whitespace is ignored on deserialize,
+    /// like in `" 2020-01-01 "`.
+    pub mod trim_whitespace {
+        use super::*;
+
+        pub use super::serialize;
+
+        pub fn deserialize<'de, D>(d: D) -> Result<::time::Date, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserialize_via(d, |s| parse_date(s.trim()))
+        }
+    }
+
+    /// `Vec<Date>` as a sequence of ISO 8601 strings.
+    pub mod vec {
+        use super::*;
+
+        pub fn serialize<S>(dates: &[::time::Date], serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serialize_seq_via(dates, serializer, |date| {
+                DateDisplay(date_to_iso8601(*date))
+            })
+        }
+
+        pub fn deserialize<'de, D>(d: D) -> Result<Vec<::time::Date>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserialize_seq_via(d, parse_date)
+        }
+    }
+
+    /// `Option<Vec<Date>>`, with `None` as null and `Some` as in [`vec`](mod@vec). For other
+    /// nestings, the `serde_with` markers compose, as in
+    /// `#[serde_as(as = "Option<Vec<Iso8601>>")]`.
+    pub mod optional_vec {
+        use super::*;
+
+        struct Dates<'a>(&'a [::time::Date]);
+
+        impl Serialize for Dates<'_> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                super::vec::serialize(self.0, serializer)
+            }
+        }
+
+        struct OwnedDates(Vec<::time::Date>);
+
+        impl<'de> Deserialize<'de> for OwnedDates {
+            fn deserialize<D>(d: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                super::vec::deserialize(d).map(OwnedDates)
+            }
+        }
+
+        pub fn serialize<S>(
+            dates: &Option<Vec<::time::Date>>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match dates {
+                Some(dates) => serializer.serialize_some(&Dates(dates)),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D>(d: D) -> Result<Option<Vec<::time::Date>>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            Ok(Option::<OwnedDates>::deserialize(d)?.map(|OwnedDates(dates)| dates))
+        }
+    }
+
+    /// `Vec<Option<Date>>`, as a sequence of ISO 8601 strings and nulls.
+    pub mod vec_optional {
+        use super::*;
+
+        pub fn serialize<S>(
+            dates: &[Option<::time::Date>],
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_seq(dates.iter().map(|date| date.map(Iso8601)))
+        }
+
+        pub fn deserialize<'de, D>(d: D) -> Result<Vec<Option<::time::Date>>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            Ok(Vec::<Option<Iso8601<::time::Date>>>::deserialize(d)?
+                .into_iter()
+                .map(|date| date.map(Iso8601::into_inner))
+                .collect())
+        }
+    }
+
+    /// ISO week dates like `2020-W01-3`. The year written is the ISO week-numbering year, which
+    /// differs from the calendar year around New Year: 2021-01-01 is `2020-W53-5`. Any ISO date
+    /// form is accepted on deserialize.
+    pub mod week {
+        use super::*;
+
+        pub fn serialize<S>(date: &::time::Date, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serialize_via(date, serializer, |date| {
+                DateDisplay(date_to_iso8601_as(*date, DateKind::Week))
+            })
+        }
+
+        pub use super::deserialize;
+    }
+
+    /// ISO week dates as a struct of numbers, like `{ "year": 2020, "week": 1, "weekday": 3 }`,
+    /// with the week-numbering year as in [`week`] and Monday as weekday 1.
+    pub mod week_struct {
+        use super::*;
+
+        use serde::de::MapAccess;
+        use serde::ser::SerializeStruct;
+
+        const FIELDS: &[&str] = &["year", "week", "weekday"];
+
+        pub fn serialize<S>(date: &::time::Date, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let (year, week) = date.iso_year_week();
+            let mut state = serializer.serialize_struct("WeekDate", 3)?;
+            state.serialize_field("year", &year)?;
+            state.serialize_field("week", &week)?;
+            state.serialize_field("weekday", &weekday_to_iso(date.weekday()))?;
+            state.end()
+        }
+
+        pub fn deserialize<'de, D>(d: D) -> Result<::time::Date, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            d.deserialize_struct("WeekDate", FIELDS, WeekVisitor)
+        }
+
+        fn week_date<E: DeError>(year: i32, week: u8, weekday: u8) -> Result<::time::Date, E> {
+            date_from_iso8601(iso8601::Date::Week {
+                year,
+                ww: week.into(),
+                d: weekday.into(),
+            })
+            .map_err(E::custom)
+        }
+
+        struct WeekVisitor;
+
+        impl<'de> Visitor<'de> for WeekVisitor {
+            type Value = ::time::Date;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a struct with year, week and weekday fields")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let year = seq
+                    .next_element()?
+                    .ok_or_else(|| DeError::invalid_length(0, &self))?;
+                let week = seq
+                    .next_element()?
+                    .ok_or_else(|| DeError::invalid_length(1, &self))?;
+                let weekday = seq
+                    .next_element()?
+                    .ok_or_else(|| DeError::invalid_length(2, &self))?;
+                week_date(year, week, weekday)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let (mut year, mut week, mut weekday) = (None, None, None);
+                while let Some(key) = map.next_key::<Cow<'_, str>>()? {
+                    match key.as_ref() {
+                        "year" if year.is_none() => year = Some(map.next_value()?),
+                        "week" if week.is_none() => week = Some(map.next_value()?),
+                        "weekday" if weekday.is_none() => weekday = Some(map.next_value()?),
+                        "year" => return Err(DeError::duplicate_field("year")),
+                        "week" => return Err(DeError::duplicate_field("week")),
+                        "weekday" => return Err(DeError::duplicate_field("weekday")),
+                        key => return Err(DeError::unknown_field(key, FIELDS)),
+                    }
+                }
+                week_date(
+                    year.ok_or_else(|| DeError::missing_field("year"))?,
+                    week.ok_or_else(|| DeError::missing_field("week"))?,
+                    weekday.ok_or_else(|| DeError::missing_field("weekday"))?,
+                )
+            }
+        }
+    }
+
+    /// Calendar dates in the basic format without separators, like `20200101`. Any ISO date form
+    /// is accepted on deserialize. Years outside `0..=9999` are an error on serialize: without
+    /// separators, an expanded year can't be told apart from the month and day.
+    pub mod basic {
+        use super::*;
+
+        pub fn serialize<S>(date: &::time::Date, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            if !(0..=9999).contains(&date.year()) {
+                return Err(SerError::custom(Iso8601SerdeError::OutOfRange {
+                    name: "year",
+                    minimum: 0,
+                    maximum: 9999,
+                    value: date.year().into(),
+                }));
+            }
+            serialize_via(date, serializer, |date| BasicDateDisplay(*date))
+        }
+
+        pub use super::deserialize;
+    }
+
+    /// Ordinal dates like `2020-366`. Any ISO date form is accepted on deserialize.
+    pub mod ordinal {
+        use super::*;
+
+        pub fn serialize<S>(date: &::time::Date, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serialize_via(date, serializer, |date| {
+                DateDisplay(date_to_iso8601_as(*date, DateKind::Ordinal))
+            })
+        }
+
+        pub use super::deserialize;
+    }
+}
+
+pub mod time_offset {
+    use super::*;
+
+    pub fn serialize<S>(
+        time: &(::time::Time, ::time::UtcOffset),
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        offset_in_range(time.1).map_err(SerError::custom)?;
+        serialize_via(time, serializer, time_offset_display)
+    }
+
+    pub fn deserialize<'de, D>(d: D) -> Result<(::time::Time, ::time::UtcOffset), D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_via(d, parse_time_offset)
+    }
+
+    pub mod optional {
+        use super::*;
+
+        pub fn serialize<S>(
+            time: &Option<(::time::Time, ::time::UtcOffset)>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            if let Some((_, offset)) = time {
+                offset_in_range(*offset).map_err(SerError::custom)?;
+            }
+            serialize_optional_via(time, serializer, time_offset_display)
+        }
+
+        pub fn deserialize<'de, D>(
+            d: D,
+        ) -> Result<Option<(::time::Time, ::time::UtcOffset)>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserialize_optional_via(d, parse_time_offset)
+        }
+    }
+
+    /// `Vec<(Time, UtcOffset)>` as a sequence of ISO 8601 strings.
+    pub mod vec {
+        use super::*;
+
+        pub fn serialize<S>(
+            times: &[(::time::Time, ::time::UtcOffset)],
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            for (_, offset) in times {
+                offset_in_range(*offset).map_err(SerError::custom)?;
+            }
+            serialize_seq_via(times, serializer, time_offset_display)
+        }
+
+        pub fn deserialize<'de, D>(d: D) -> Result<Vec<(::time::Time, ::time::UtcOffset)>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserialize_seq_via(d, parse_time_offset)
+        }
+    }
+
+    /// Same as the parent module, but leading and trailing whitespace is ignored on deserialize,
+    /// like in `" 12:00:00+09:00 "`.
+    pub mod trim_whitespace {
+        use super::*;
+
+        pub use super::serialize;
+
+        pub fn deserialize<'de, D>(d: D) -> Result<(::time::Time, ::time::UtcOffset), D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserialize_via(d, |s| parse_time_offset(s.trim()))
+        }
+    }
+
+    /// Same as the parent module, but reduced precision times are also accepted on deserialize:
+    /// `12:30` and `12:30+09:00` have zero seconds, and `12` and `12Z` zero minutes as well.
+    /// Anything else still has to be a complete time.
+    pub mod reduced {
+        use super::*;
+
+        pub use super::serialize;
+
+        pub fn deserialize<'de, D>(d: D) -> Result<(::time::Time, ::time::UtcOffset), D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserialize_via(d, parse_reduced_time_offset)
+        }
+    }
+
+    /// Same as the parent module, but a full datetime is also accepted on deserialize, keeping its
+    /// time and offset, so `2020-01-01T12:00:00+09:00` reads as `12:00:00+09:00`. Whether the
+    /// input is a datetime is decided by its `T`, as the time parser would take the date of a
+    /// datetime for a time and ignore the rest.
+    pub mod from_datetime_or_time {
+        use super::*;
+
+        pub use super::serialize;
+
+        pub fn deserialize<'de, D>(d: D) -> Result<(::time::Time, ::time::UtcOffset), D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserialize_via(d, |s| {
+                if s.contains('T') {
+                    parse_datetime(s).map(|time| (time.time(), time.offset()))
+                } else {
+                    parse_time_offset(s)
+                }
+            })
+        }
+    }
+}
+
+pub mod utc_offset {
+    use super::*;
+
+    pub fn serialize<S>(offset: &::time::UtcOffset, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        offset_in_range(*offset).map_err(SerError::custom)?;
+        serializer.collect_str(&OffsetDisplay {
+            offset: *offset,
+            zulu: true,
+        })
+    }
+
+    pub fn deserialize<'de, D>(d: D) -> Result<::time::UtcOffset, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_via(d, parse_utc_offset)
+    }
+
+    pub mod optional {
+        use super::*;
+
+        pub fn serialize<S>(
+            offset: &Option<::time::UtcOffset>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            if let Some(offset) = offset {
+                offset_in_range(*offset).map_err(SerError::custom)?;
+            }
+            serialize_optional_via(offset, serializer, |offset| OffsetDisplay {
+                offset: *offset,
+                zulu: true,
+            })
+        }
+
+        pub fn deserialize<'de, D>(d: D) -> Result<Option<::time::UtcOffset>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserialize_optional_via(d, parse_utc_offset)
+        }
+    }
+
+    /// `Vec<UtcOffset>` as a sequence of ISO 8601 UTC offsets.
+    pub mod vec {
+        use super::*;
+
+        pub fn serialize<S>(offsets: &[::time::UtcOffset], serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            for offset in offsets {
+                offset_in_range(*offset).map_err(SerError::custom)?;
+            }
+            serialize_seq_via(offsets, serializer, |offset| OffsetDisplay {
+                offset: *offset,
+                zulu: true,
+            })
+        }
+
+        pub fn deserialize<'de, D>(d: D) -> Result<Vec<::time::UtcOffset>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserialize_seq_via(d, parse_utc_offset)
+        }
+    }
+
+    /// The total offset in minutes as an integer, like `540` for `+09:00` and `-330` for
+    /// `-05:30`, for compact time zone tables. Offsets with seconds and offsets beyond ±14:00 are
+    /// rejected both ways.
+    pub mod minutes {
+        use super::*;
+
+        pub fn serialize<S>(offset: &::time::UtcOffset, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_i16(offset_minutes(*offset).map_err(SerError::custom)?)
+        }
+
+        pub fn deserialize<'de, D>(d: D) -> Result<::time::UtcOffset, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            offset_from_minutes(i16::deserialize(d)?)
+        }
+
+        fn offset_minutes(offset: ::time::UtcOffset) -> Result<i16, Iso8601SerdeError> {
+            offset_in_range(offset)?;
+            whole_minute_offset(offset)?;
+            Ok(offset.as_minutes())
+        }
+
+        fn offset_from_minutes<E: DeError>(minutes: i16) -> Result<::time::UtcOffset, E> {
+            if !(-14 * 60..=14 * 60).contains(&minutes) {
+                return Err(E::invalid_value(
+                    Unexpected::Signed(i64::from(minutes)),
+                    &"a UTC offset in minutes within -840..=840",
+                ));
+            }
+            Ok(::time::UtcOffset::minutes(minutes))
+        }
+
+        /// `Vec<UtcOffset>` as a sequence of offsets in minutes.
+        pub mod vec {
+            use super::*;
+
+            pub fn serialize<S>(
+                offsets: &[::time::UtcOffset],
+                serializer: S,
+            ) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let minutes = offsets
+                    .iter()
+                    .map(|offset| offset_minutes(*offset))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(SerError::custom)?;
+                serializer.collect_seq(minutes)
+            }
+
+            pub fn deserialize<'de, D>(d: D) -> Result<Vec<::time::UtcOffset>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                Vec::<i16>::deserialize(d)?
+                    .into_iter()
+                    .map(offset_from_minutes)
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Wall-clock times without an offset. An offset present in the input is accepted and dropped,
+/// keeping the wall-clock time as written. Named `time_of_day` so that a glob import of this
+/// crate doesn't shadow the `time` crate.
+///
+/// The fraction is written from the full nanosecond, as milliseconds by default and with as many
+/// more digits as it takes to stay lossless, so `12:00:00.000999` isn't cut to `12:00:00.000`.
+pub mod time_of_day {
+    use super::*;
+
+    pub fn serialize<S>(time: &::time::Time, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_via(time, serializer, |time| {
+            TimeDisplay(*time, None, Fraction::Lossless)
+        })
+    }
+
+    pub fn deserialize<'de, D>(d: D) -> Result<::time::Time, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_via(d, |s| parse_time_offset(s).map(|(time, _)| time))
+    }
+
+    pub mod optional {
+        use super::*;
+
+        pub fn serialize<S>(time: &Option<::time::Time>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serialize_optional_via(time, serializer, |time| {
+                TimeDisplay(*time, None, Fraction::Lossless)
+            })
+        }
+
+        pub fn deserialize<'de, D>(d: D) -> Result<Option<::time::Time>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserialize_optional_via(d, |s| parse_time_offset(s).map(|(time, _)| time))
+        }
+    }
+
+    /// Same as the parent module, but leading and trailing whitespace is ignored on deserialize,
+    /// like in `" 12:00:00 "`.
+    pub mod trim_whitespace {
+        use super::*;
+
+        pub use super::serialize;
+
+        pub fn deserialize<'de, D>(d: D) -> Result<::time::Time, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserialize_via(d, |s| parse_time_offset(s.trim()).map(|(time, _)| time))
+        }
+    }
+
+    /// Same as the parent module, but reduced precision times like `09:00` and `09` are also
+    /// accepted on deserialize, with the missing components as zero.
+    pub mod reduced {
+        use super::*;
+
+        pub use super::serialize;
+
+        pub fn deserialize<'de, D>(d: D) -> Result<::time::Time, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserialize_via(d, |s| parse_reduced_time_offset(s).map(|(time, _)| time))
+        }
+    }
+}
+
+/// Date-times without an offset. An offset present in the input is accepted and dropped,
+/// keeping the wall-clock date and time as written.
+pub mod primitive_datetime {
+    use super::*;
+
+    pub fn serialize<S>(
+        datetime: &::time::PrimitiveDateTime,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_via(datetime, serializer, |datetime| {
+            DateTimeDisplay(*datetime, None, Fraction::Lossless)
+        })
+    }
+
+    pub fn deserialize<'de, D>(d: D) -> Result<::time::PrimitiveDateTime, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_via(d, |s| {
+            parse_primitive_datetime(s).map(|(datetime, _)| datetime)
+        })
+    }
+
+    pub mod optional {
+        use super::*;
+
+        pub fn serialize<S>(
+            datetime: &Option<::time::PrimitiveDateTime>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serialize_optional_via(datetime, serializer, |datetime| {
+                DateTimeDisplay(*datetime, None, Fraction::Lossless)
+            })
+        }
+
+        pub fn deserialize<'de, D>(d: D) -> Result<Option<::time::PrimitiveDateTime>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserialize_optional_via(d, |s| {
+                parse_primitive_datetime(s).map(|(datetime, _)| datetime)
+            })
+        }
+    }
+
+    /// `Vec<PrimitiveDateTime>` as a sequence of ISO 8601 strings.
+    pub mod vec {
+        use super::*;
+
+        pub fn serialize<S>(
+            datetimes: &[::time::PrimitiveDateTime],
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serialize_seq_via(datetimes, serializer, |datetime| {
+                DateTimeDisplay(*datetime, None, Fraction::Lossless)
+            })
+        }
+
+        pub fn deserialize<'de, D>(d: D) -> Result<Vec<::time::PrimitiveDateTime>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserialize_seq_via(d, |s| {
+                parse_primitive_datetime(s).map(|(datetime, _)| datetime)
+            })
+        }
+    }
+}
+
+/// A `(Date, Time)` pair as a single date-time without an offset, like [`primitive_datetime`].
+pub mod date_time {
+    use super::*;
+
+    pub fn serialize<S>(
+        date_time: &(::time::Date, ::time::Time),
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_via(date_time, serializer, |&(date, time)| {
+            DateTimeDisplay(
+                ::time::PrimitiveDateTime::new(date, time),
+                None,
+                Fraction::Lossless,
+            )
+        })
+    }
+
+    pub fn deserialize<'de, D>(d: D) -> Result<(::time::Date, ::time::Time), D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_via(d, |s| {
+            parse_primitive_datetime(s).map(|(datetime, _)| (datetime.date(), datetime.time()))
+        })
     }
 }
 
-pub fn datetime_from_iso8601(
-    iso8601::DateTime { date, time }: iso8601::DateTime,
-) -> Result<time::OffsetDateTime, time::error::ComponentRange> {
-    let (time, offset) = time_from_iso8601(time);
-    Ok(date_from_iso8601(date)?
-        .with_time(time?)
-        .assume_offset(offset))
-}
-
-pub mod datetime {
+/// `time::Duration` as an ISO 8601 duration like `P1DT2H30M0.5S`. Days are the largest unit
+/// written; years and months are rejected on deserialize since their length depends on the
+/// calendar. Fractional seconds are kept to the nanosecond.
+///
+/// The output is canonical, so equal durations are always written the same way: weeks are never
+/// used (`P1W` is read as `P7D`), each unit is carried into the next larger one up to days, and
+/// zero units are left out. Negative durations get a leading `-`, like `-PT1H30M`.
+///
+/// Durations longer than `u32::MAX` days either way are rejected on serialize, as that is the
+/// most days the parser reads back.
+pub mod duration {
     use super::*;
 
-    pub fn serialize<S>(time: &time::OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error>
+    pub fn serialize<S>(duration: &::time::Duration, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        serializer.serialize_str(&datetime_to_iso8601(*time).to_string())
+        let days = duration.whole_days().checked_abs();
+        if !matches!(days, Some(days) if days <= MAX_DURATION_DAYS) {
+            return Err(SerError::custom(Iso8601SerdeError::DurationRange));
+        }
+        serializer.collect_str(&DurationDisplay(*duration))
     }
 
-    pub fn deserialize<'de, D>(d: D) -> Result<time::OffsetDateTime, D::Error>
+    pub fn deserialize<'de, D>(d: D) -> Result<::time::Duration, D::Error>
     where
         D: Deserializer<'de>,
     {
-        iso8601::datetime(Cow::<'_, str>::deserialize(d)?.as_ref())
-            .map_err(DeError::custom)
-            .and_then(|time| datetime_from_iso8601(time).map_err(DeError::custom))
+        deserialize_via(d, parse_duration)
     }
 
     pub mod optional {
         use super::*;
 
         pub fn serialize<S>(
-            time: &Option<time::OffsetDateTime>,
+            duration: &Option<::time::Duration>,
             serializer: S,
         ) -> Result<S::Ok, S::Error>
         where
             S: Serializer,
         {
-            match time {
-                Some(time) => serializer.serialize_str(&datetime_to_iso8601(*time).to_string()),
+            match duration {
+                Some(duration) => super::serialize(duration, serializer),
                 None => serializer.serialize_none(),
             }
         }
 
-        pub fn deserialize<'de, D>(d: D) -> Result<Option<time::OffsetDateTime>, D::Error>
+        pub fn deserialize<'de, D>(d: D) -> Result<Option<::time::Duration>, D::Error>
         where
             D: Deserializer<'de>,
         {
-            let optional = Option::<Cow<'_, str>>::deserialize(d)?;
-            match optional {
-                Some(s) => iso8601::datetime(s.as_ref())
-                    .map_err(DeError::custom)
-                    .and_then(|time| datetime_from_iso8601(time).map_err(DeError::custom))
-                    .map(Some),
-                None => Ok(None),
+            deserialize_optional_via(d, parse_duration)
+        }
+    }
+
+    /// A number of seconds instead of an ISO 8601 string: an integer for whole seconds, like `90`,
+    /// and a float otherwise, like `1.5`. Formats that aren't human-readable always get a float.
+    /// An `f64` has about 15 significant digits, so durations past about 100 days lose their
+    /// nanoseconds, and past about 285 years their microseconds. Floats are rounded to the nearest
+    /// nanosecond, so `2.01` is 2 seconds and 10 milliseconds exactly.
+    pub mod seconds {
+        use super::*;
+
+        pub fn serialize<S>(duration: &::time::Duration, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            if duration.subsec_nanoseconds() == 0 && serializer.is_human_readable() {
+                serializer.serialize_i64(duration.whole_seconds())
+            } else {
+                serializer.serialize_f64(duration.as_seconds_f64())
+            }
+        }
+
+        pub fn deserialize<'de, D>(d: D) -> Result<::time::Duration, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            if d.is_human_readable() {
+                d.deserialize_any(SecondsVisitor)
+            } else {
+                d.deserialize_f64(SecondsVisitor)
+            }
+        }
+
+        struct SecondsVisitor;
+
+        impl<'de> Visitor<'de> for SecondsVisitor {
+            type Value = ::time::Duration;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a number of seconds")
+            }
+
+            fn visit_i64<E: DeError>(self, seconds: i64) -> Result<Self::Value, E> {
+                Ok(::time::Duration::seconds(seconds))
+            }
+
+            fn visit_u64<E: DeError>(self, seconds: u64) -> Result<Self::Value, E> {
+                match i64::try_from(seconds) {
+                    Ok(seconds) => self.visit_i64(seconds),
+                    Err(_) => Err(E::invalid_value(Unexpected::Unsigned(seconds), &self)),
+                }
+            }
+
+            fn visit_f64<E: DeError>(self, seconds: f64) -> Result<Self::Value, E> {
+                if !seconds.is_finite() || seconds.abs() >= i64::MAX as f64 {
+                    return Err(E::invalid_value(Unexpected::Float(seconds), &self));
+                }
+                // `Duration::seconds_f64` truncates, which turns `2.01` into 2.009999999 seconds.
+                let whole = seconds as i64;
+                let nanoseconds = (seconds - whole as f64) * 1_000_000_000.;
+                let nanoseconds = if nanoseconds < 0. {
+                    nanoseconds - 0.5
+                } else {
+                    nanoseconds + 0.5
+                };
+                Ok(::time::Duration::seconds(whole)
+                    + ::time::Duration::nanoseconds(nanoseconds as i64))
             }
         }
     }
 }
 
-pub mod date {
+pub mod weekday {
     use super::*;
 
-    pub fn serialize<S>(time: &time::Date, serializer: S) -> Result<S::Ok, S::Error>
+    pub fn serialize<S>(weekday: &::time::Weekday, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        serializer.serialize_str(&date_to_iso8601(*time).to_string())
+        serializer.serialize_u8(weekday_to_iso(*weekday))
     }
 
-    pub fn deserialize<'de, D>(d: D) -> Result<time::Date, D::Error>
+    pub fn deserialize<'de, D>(d: D) -> Result<::time::Weekday, D::Error>
     where
         D: Deserializer<'de>,
     {
-        iso8601::date(Cow::<'_, str>::deserialize(d)?.as_ref())
-            .map_err(DeError::custom)
-            .and_then(|time| date_from_iso8601(time).map_err(DeError::custom))
+        from_number(u8::deserialize(d)?)
     }
 
-    pub mod optional {
+    fn from_number<E: DeError>(number: u8) -> Result<::time::Weekday, E> {
+        weekday_from_iso(number).map_err(|_| {
+            E::invalid_value(
+                Unexpected::Unsigned(number.into()),
+                &"an ISO 8601 weekday number in 1..=7",
+            )
+        })
+    }
+
+    fn serialize_seq<S>(weekdays: &[::time::Weekday], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(weekdays.iter().map(|weekday| weekday_to_iso(*weekday)))
+    }
+
+    fn deserialize_seq<'de, D>(d: D, unique: bool) -> Result<Vec<::time::Weekday>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let numbers = Vec::<u8>::deserialize(d)?;
+        let mut weekdays = Vec::with_capacity(numbers.len());
+        for (index, number) in numbers.into_iter().enumerate() {
+            let weekday = from_number::<D::Error>(number).map_err(|err| {
+                DeError::custom(format_args!("invalid element at index {}: {}", index, err))
+            })?;
+            if unique && weekdays.contains(&weekday) {
+                return Err(DeError::custom(format_args!(
+                    "invalid element at index {}: duplicate weekday {}",
+                    index, number
+                )));
+            }
+            weekdays.push(weekday);
+        }
+        Ok(weekdays)
+    }
+
+    /// Weekdays as an array of ISO 8601 weekday numbers, in the given order.
+    pub mod vec {
         use super::*;
 
-        pub fn serialize<S>(time: &Option<time::Date>, serializer: S) -> Result<S::Ok, S::Error>
+        pub fn serialize<S>(weekdays: &[::time::Weekday], serializer: S) -> Result<S::Ok, S::Error>
         where
             S: Serializer,
         {
-            match time {
-                Some(time) => serializer.serialize_str(&date_to_iso8601(*time).to_string()),
-                None => serializer.serialize_none(),
-            }
+            serialize_seq(weekdays, serializer)
         }
 
-        pub fn deserialize<'de, D>(d: D) -> Result<Option<time::Date>, D::Error>
+        pub fn deserialize<'de, D>(d: D) -> Result<Vec<::time::Weekday>, D::Error>
         where
             D: Deserializer<'de>,
         {
-            let optional = Option::<Cow<'_, str>>::deserialize(d)?;
-            match optional {
-                Some(s) => iso8601::date(s.as_ref())
-                    .map_err(DeError::custom)
-                    .and_then(|time| date_from_iso8601(time).map_err(DeError::custom))
-                    .map(Some),
-                None => Ok(None),
-            }
+            deserialize_seq(d, false)
+        }
+    }
+
+    /// Like [`vec`](mod@vec), but deserializing rejects a weekday that appears more than once.
+    pub mod set {
+        use super::*;
+
+        pub fn serialize<S>(weekdays: &[::time::Weekday], serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serialize_seq(weekdays, serializer)
+        }
+
+        pub fn deserialize<'de, D>(d: D) -> Result<Vec<::time::Weekday>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserialize_seq(d, true)
         }
     }
 }
 
-pub mod time_offset {
+/// A month of the year as a `u8`, the way `time` 0.2 represents it, written as the ISO 8601 month
+/// number in `1..=12`.
+pub mod month {
+    use super::*;
+
+    fn check(month: u8) -> Result<u8, Iso8601SerdeError> {
+        match month {
+            1..=12 => Ok(month),
+            _ => Err(Iso8601SerdeError::OutOfRange {
+                name: "month",
+                minimum: 1,
+                maximum: 12,
+                value: month.into(),
+            }),
+        }
+    }
+
+    pub fn serialize<S>(month: &u8, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u8(check(*month).map_err(SerError::custom)?)
+    }
+
+    pub fn deserialize<'de, D>(d: D) -> Result<u8, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let number = u8::deserialize(d)?;
+        check(number).map_err(|_| {
+            DeError::invalid_value(
+                Unexpected::Unsigned(number.into()),
+                &"an ISO 8601 month number in 1..=12",
+            )
+        })
+    }
+}
+
+/// A `Range<Date>` as an ISO 8601 interval of two dates, like `2020-01-01/2020-01-31`. The end
+/// is written as it is in the range, so it stays exclusive. Deserializing rejects an end before
+/// the start.
+pub mod date_range {
+    use super::*;
+
+    use core::ops::Range;
+
+    pub fn serialize<S>(range: &Range<::time::Date>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if range.end < range.start {
+            return Err(SerError::custom(Iso8601SerdeError::ReversedInterval));
+        }
+        serialize_via(range, serializer, |range| {
+            IntervalDisplay(
+                DateDisplay(date_to_iso8601(range.start)),
+                DateDisplay(date_to_iso8601(range.end)),
+            )
+        })
+    }
+
+    pub fn deserialize<'de, D>(d: D) -> Result<Range<::time::Date>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_via(d, parse_date_range)
+    }
+}
+
+/// A `(start, end)` pair of datetimes as an ISO 8601 interval, like
+/// `2020-01-01T00:00:00Z/2020-01-02T00:00:00Z`. Deserializing also takes a duration for the end,
+/// like `2020-01-01T00:00:00Z/P1D`, which is added to the start and kept in its offset. Either
+/// way an end before the start is rejected.
+pub mod interval {
     use super::*;
 
     pub fn serialize<S>(
-        (time, offset): &(time::Time, time::UtcOffset),
+        interval: &(::time::OffsetDateTime, ::time::OffsetDateTime),
         serializer: S,
     ) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        serializer.serialize_str(&time_to_iso8601(*time, *offset).to_string())
+        if interval.1 < interval.0 {
+            return Err(SerError::custom(Iso8601SerdeError::ReversedInterval));
+        }
+        datetime_in_range(interval.0).map_err(SerError::custom)?;
+        datetime_in_range(interval.1).map_err(SerError::custom)?;
+        serialize_via(interval, serializer, |&(start, end)| {
+            IntervalDisplay(DateTimeDisplay::from(start), DateTimeDisplay::from(end))
+        })
+    }
+
+    pub fn deserialize<'de, D>(
+        d: D,
+    ) -> Result<(::time::OffsetDateTime, ::time::OffsetDateTime), D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_via(d, parse_interval)
+    }
+}
+
+/// Wraps a `time` value to de/serialize it as ISO 8601 without `#[serde(with = "...")]`, e.g. in a
+/// `Vec<Iso8601<OffsetDateTime>>`.
+///
+/// It also works as a map key, where `#[serde(with = "...")]` can't reach: a
+/// `BTreeMap<Iso8601<Date>, u32>` is a JSON object keyed by ISO 8601 dates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Iso8601<T>(pub T);
+
+impl<T> Iso8601<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for Iso8601<T> {
+    fn from(value: T) -> Self {
+        Iso8601(value)
+    }
+}
+
+impl<T> core::ops::Deref for Iso8601<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> core::ops::DerefMut for Iso8601<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+pub type Iso8601DateTime = Iso8601<::time::OffsetDateTime>;
+pub type Iso8601Date = Iso8601<::time::Date>;
+pub type Iso8601Time = Iso8601<(::time::Time, ::time::UtcOffset)>;
+
+// `Display` writes what the human-readable `serialize` does, and `FromStr` parses it back like
+// `deserialize`, so the wrappers also work outside serde, e.g. as command line arguments.
+macro_rules! iso8601_newtype {
+    ($ty:ty, $module:path, $name:literal, $format:literal, $parse:ident, $display:expr) => {
+        impl From<Iso8601<$ty>> for $ty {
+            fn from(Iso8601(value): Iso8601<$ty>) -> Self {
+                value
+            }
+        }
+
+        impl fmt::Display for Iso8601<$ty> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&($display)(&self.0), f)
+            }
+        }
+
+        impl core::str::FromStr for Iso8601<$ty> {
+            type Err = Iso8601SerdeError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $parse(s).map(Iso8601)
+            }
+        }
+
+        impl TryFrom<&str> for Iso8601<$ty> {
+            type Error = Iso8601SerdeError;
+
+            fn try_from(s: &str) -> Result<Self, Self::Error> {
+                $parse(s).map(Iso8601)
+            }
+        }
+
+        impl Serialize for Iso8601<$ty> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                use $module as module;
+                module::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for Iso8601<$ty> {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                use $module as module;
+                module::deserialize(deserializer).map(Iso8601)
+            }
+        }
+
+        /// A `string` with the matching JSON Schema `format`.
+        #[cfg(feature = "schemars")]
+        impl ::schemars::JsonSchema for Iso8601<$ty> {
+            fn is_referenceable() -> bool {
+                false
+            }
+
+            fn schema_name() -> String {
+                String::from($name)
+            }
+
+            fn json_schema(_: &mut ::schemars::gen::SchemaGenerator) -> ::schemars::schema::Schema {
+                ::schemars::schema::SchemaObject {
+                    instance_type: Some(::schemars::schema::InstanceType::String.into()),
+                    format: Some(String::from($format)),
+                    ..Default::default()
+                }
+                .into()
+            }
+        }
+    };
+}
+
+iso8601_newtype!(
+    ::time::OffsetDateTime,
+    datetime,
+    "DateTime",
+    "date-time",
+    parse_datetime,
+    |datetime: &::time::OffsetDateTime| DateTimeDisplay::from(*datetime)
+);
+iso8601_newtype!(
+    ::time::Date,
+    date,
+    "Date",
+    "date",
+    parse_date,
+    |date: &::time::Date| DateDisplay(date_to_iso8601(*date))
+);
+iso8601_newtype!(
+    (::time::Time, ::time::UtcOffset),
+    time_offset,
+    "Time",
+    "time",
+    parse_time_offset,
+    time_offset_display
+);
+
+// Both sides of these conversions are foreign, so they go through `Iso8601<T>`, as in
+// `Iso8601::<OffsetDateTime>::try_from(datetime)?.into_inner()`.
+macro_rules! iso8601_conversion {
+    ($ty:ty, $iso8601:ty, $to:ident, $from:ident) => {
+        impl From<Iso8601<$ty>> for $iso8601 {
+            fn from(Iso8601(value): Iso8601<$ty>) -> Self {
+                $to(value)
+            }
+        }
+
+        impl TryFrom<$iso8601> for Iso8601<$ty> {
+            type Error = Iso8601SerdeError;
+
+            fn try_from(value: $iso8601) -> Result<Self, Self::Error> {
+                $from(value).map(Iso8601)
+            }
+        }
+    };
+}
+
+iso8601_conversion!(
+    ::time::OffsetDateTime,
+    iso8601::DateTime,
+    datetime_to_iso8601,
+    datetime_from_iso8601
+);
+iso8601_conversion!(
+    ::time::Date,
+    iso8601::Date,
+    date_to_iso8601,
+    date_from_iso8601
+);
+
+/// A `time::Date` that remembers whether it was written as a calendar, week or ordinal date, and
+/// is serialized back in the same form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PreservingDate {
+    date: ::time::Date,
+    iso8601: iso8601::Date,
+}
+
+impl PreservingDate {
+    /// A calendar date like `2020-01-01`.
+    pub const fn new(date: ::time::Date) -> Self {
+        PreservingDate {
+            date,
+            iso8601: date_to_iso8601(date),
+        }
+    }
+
+    /// A week date like `2020-W01-3`.
+    pub fn week(date: ::time::Date) -> Self {
+        PreservingDate {
+            date,
+            iso8601: date_to_iso8601_week(date),
+        }
+    }
+
+    /// An ordinal date like `2020-001`.
+    pub const fn ordinal(date: ::time::Date) -> Self {
+        PreservingDate {
+            date,
+            iso8601: date_to_iso8601_ordinal(date),
+        }
+    }
+
+    pub const fn date(&self) -> ::time::Date {
+        self.date
+    }
+
+    pub const fn iso8601(&self) -> iso8601::Date {
+        self.iso8601
+    }
+
+    pub const fn kind(&self) -> DateKind {
+        iso8601_date_kind(&self.iso8601)
+    }
+}
+
+impl From<::time::Date> for PreservingDate {
+    fn from(date: ::time::Date) -> Self {
+        PreservingDate::new(date)
+    }
+}
+
+impl From<PreservingDate> for ::time::Date {
+    fn from(date: PreservingDate) -> Self {
+        date.date
+    }
+}
+
+impl Serialize for PreservingDate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&DateDisplay(self.iso8601))
+    }
+}
+
+impl<'de> Deserialize<'de> for PreservingDate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_via(deserializer, |s| {
+            let iso8601 = parse_iso8601_date(s)?;
+            Ok(PreservingDate {
+                date: date_from_iso8601(iso8601)?,
+                iso8601,
+            })
+        })
+    }
+}
+
+/// A `time::OffsetDateTime` that remembers the exact string it was deserialized from, and writes
+/// it back byte for byte, for forwarding timestamps unchanged. One built with [`RawDatetime::new`]
+/// has no string and is written as [`datetime::serialize`] would.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RawDatetime {
+    datetime: ::time::OffsetDateTime,
+    raw: Option<String>,
+}
+
+impl RawDatetime {
+    pub const fn new(datetime: ::time::OffsetDateTime) -> Self {
+        RawDatetime {
+            datetime,
+            raw: None,
+        }
+    }
+
+    pub const fn datetime(&self) -> ::time::OffsetDateTime {
+        self.datetime
+    }
+
+    /// The string it was deserialized from, if it was.
+    pub fn raw(&self) -> Option<&str> {
+        self.raw.as_deref()
+    }
+}
+
+impl From<::time::OffsetDateTime> for RawDatetime {
+    fn from(datetime: ::time::OffsetDateTime) -> Self {
+        RawDatetime::new(datetime)
+    }
+}
+
+impl From<RawDatetime> for ::time::OffsetDateTime {
+    fn from(datetime: RawDatetime) -> Self {
+        datetime.datetime
+    }
+}
+
+impl Serialize for RawDatetime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match &self.raw {
+            Some(raw) => serializer.serialize_str(raw),
+            None => {
+                datetime_in_range(self.datetime).map_err(SerError::custom)?;
+                serializer.collect_str(&DateTimeDisplay::from(self.datetime))
+            }
+        }
     }
+}
 
-    pub fn deserialize<'de, D>(d: D) -> Result<(time::Time, time::UtcOffset), D::Error>
+impl<'de> Deserialize<'de> for RawDatetime {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        iso8601::time(Cow::<'_, str>::deserialize(d)?.as_ref())
-            .map_err(DeError::custom)
-            .and_then(|time| {
-                let (time, offset) = time_from_iso8601(time);
-                time.map_err(DeError::custom).map(|t| (t, offset))
+        deserialize_via(deserializer, |s| {
+            Ok(RawDatetime {
+                datetime: parse_datetime(s)?,
+                raw: Some(s.to_string()),
             })
+        })
+    }
+}
+
+/// Markers for `#[serde_as(as = "...")]`, delegating to the modules above. They nest inside
+/// `serde_with`'s own containers, so `Option<Vec<Iso8601>>` or `Vec<Option<Iso8601>>` work for
+/// any combination of options and collections.
+#[cfg(feature = "serde_with")]
+pub mod serde_with {
+    use ::serde_with::{DeserializeAs, SerializeAs};
+
+    use super::*;
+
+    macro_rules! serde_as {
+        ($marker:ty, $ty:ty, $module:path) => {
+            impl SerializeAs<$ty> for $marker {
+                fn serialize_as<S>(source: &$ty, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    use $module as module;
+                    module::serialize(source, serializer)
+                }
+            }
+
+            impl<'de> DeserializeAs<'de, $ty> for $marker {
+                fn deserialize_as<D>(deserializer: D) -> Result<$ty, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    use $module as module;
+                    module::deserialize(deserializer)
+                }
+            }
+        };
     }
 
-    pub mod optional {
-        use super::*;
+    pub struct Iso8601;
 
-        pub fn serialize<S>(
-            time: &Option<(time::Time, time::UtcOffset)>,
+    serde_as!(Iso8601, ::time::OffsetDateTime, super::datetime);
+    serde_as!(Iso8601, ::time::Date, super::date);
+    serde_as!(
+        Iso8601,
+        (::time::Time, ::time::UtcOffset),
+        super::time_offset
+    );
+    serde_as!(Iso8601, ::time::UtcOffset, super::utc_offset);
+    serde_as!(Iso8601, ::time::Time, super::time_of_day);
+    serde_as!(
+        Iso8601,
+        ::time::PrimitiveDateTime,
+        super::primitive_datetime
+    );
+    serde_as!(Iso8601, ::time::Duration, super::duration);
+
+    /// Serializes UTC as `Z`, like [`datetime::zulu`].
+    pub struct Iso8601Zulu;
+
+    serde_as!(Iso8601Zulu, ::time::OffsetDateTime, super::datetime::zulu);
+
+    /// Serializes week dates, like [`date::week`].
+    pub struct Iso8601Week;
+
+    serde_as!(Iso8601Week, ::time::Date, super::date::week);
+
+    /// Serializes ordinal dates, like [`date::ordinal`].
+    pub struct Iso8601Ordinal;
+
+    serde_as!(Iso8601Ordinal, ::time::Date, super::date::ordinal);
+
+    /// Options for [`Iso8601Custom`], as associated constants so one marker type covers any
+    /// combination. Each one defaults to what [`datetime`] does.
+    pub trait Iso8601Config {
+        /// Writes UTC as `Z` rather than `+00:00`.
+        const ZULU: bool = false;
+        /// Writes exactly this many fractional digits, at most 9, truncating the rest. `None`
+        /// writes as many groups of three as it takes to stay lossless.
+        const FRACTION_DIGITS: Option<u32> = None;
+        /// Reads a datetime without an offset as UTC, like [`datetime::assume_utc`].
+        const ASSUME_UTC: bool = false;
+    }
+
+    /// Serializes `OffsetDateTime` as configured by `C`, like
+    /// `#[serde_as(as = "Iso8601Custom<ZuluMillis>")]`.
+    pub struct Iso8601Custom<C>(core::marker::PhantomData<C>);
+
+    impl<C: Iso8601Config> SerializeAs<::time::OffsetDateTime> for Iso8601Custom<C> {
+        fn serialize_as<S>(
+            source: &::time::OffsetDateTime,
             serializer: S,
         ) -> Result<S::Ok, S::Error>
         where
             S: Serializer,
         {
-            match time {
-                Some((time, offset)) => {
-                    serializer.serialize_str(&time_to_iso8601(*time, *offset).to_string())
-                }
-                None => serializer.serialize_none(),
-            }
+            let fraction = match C::FRACTION_DIGITS {
+                Some(digits) => Fraction::Fixed(digits.min(9)),
+                None => Fraction::Lossless,
+            };
+            serialize_with_fraction(source, serializer, fraction, C::ZULU)
         }
+    }
 
-        pub fn deserialize<'de, D>(d: D) -> Result<Option<(time::Time, time::UtcOffset)>, D::Error>
+    impl<'de, C: Iso8601Config> DeserializeAs<'de, ::time::OffsetDateTime> for Iso8601Custom<C> {
+        fn deserialize_as<D>(deserializer: D) -> Result<::time::OffsetDateTime, D::Error>
         where
             D: Deserializer<'de>,
         {
-            let optional = Option::<Cow<'_, str>>::deserialize(d)?;
-            match optional {
-                Some(s) => iso8601::time(s.as_ref())
-                    .map_err(DeError::custom)
-                    .and_then(|time| {
-                        let (time, offset) = time_from_iso8601(time);
-                        time.map_err(DeError::custom).map(|t| Some((t, offset)))
-                    }),
-                None => Ok(None),
+            if C::ASSUME_UTC {
+                datetime::assume_utc::deserialize(deserializer)
+            } else {
+                datetime::deserialize(deserializer)
             }
         }
     }
+
+    /// UTC as `Z`, with exactly three fractional digits, like `2020-01-01T00:00:00.000Z`.
+    pub struct ZuluMillis;
+
+    impl Iso8601Config for ZuluMillis {
+        const ZULU: bool = true;
+        const FRACTION_DIGITS: Option<u32> = Some(3);
+    }
 }
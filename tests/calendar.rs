@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+use time::Weekday;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Week {
+    #[serde(with = "time_iso8601_serde::weekday")]
+    starts_on: Weekday,
+}
+
+#[test]
+fn weekdays_are_iso_numbers_from_monday() {
+    for (starts_on, json) in [
+        (Weekday::Monday, r#"{"starts_on":1}"#),
+        (Weekday::Sunday, r#"{"starts_on":7}"#),
+    ] {
+        assert_eq!(serde_json::to_string(&Week { starts_on }).unwrap(), json);
+        assert_eq!(
+            serde_json::from_str::<Week>(json).unwrap().starts_on,
+            starts_on
+        );
+    }
+    for number in [0, 8] {
+        let err = serde_json::from_str::<Week>(&format!(r#"{{"starts_on":{}}}"#, number))
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains(&format!("invalid value: integer `{}`", number))
+                && err.contains("an ISO 8601 weekday number in 1..=7"),
+            "{}",
+            err
+        );
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Budget {
+    #[serde(with = "time_iso8601_serde::month")]
+    month: u8,
+}
+
+#[test]
+fn months_are_numbers_from_one_to_twelve() {
+    for month in [1, 12] {
+        let json = serde_json::to_string(&Budget { month }).unwrap();
+        assert_eq!(json, format!(r#"{{"month":{}}}"#, month));
+        assert_eq!(serde_json::from_str::<Budget>(&json).unwrap().month, month);
+    }
+    for number in [0, 13] {
+        let err = serde_json::from_str::<Budget>(&format!(r#"{{"month":{}}}"#, number))
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains(&format!("invalid value: integer `{}`", number))
+                && err.contains("an ISO 8601 month number in 1..=12"),
+            "{}",
+            err
+        );
+        assert!(serde_json::to_string(&Budget { month: number }).is_err());
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Schedule {
+    #[serde(with = "time_iso8601_serde::weekday::vec")]
+    days: Vec<Weekday>,
+    #[serde(with = "time_iso8601_serde::weekday::set")]
+    closed: Vec<Weekday>,
+}
+
+#[test]
+fn weekday_sequences_keep_their_order() {
+    let schedule = Schedule {
+        days: vec![Weekday::Friday, Weekday::Monday, Weekday::Friday],
+        closed: vec![Weekday::Sunday, Weekday::Saturday],
+    };
+    let json = serde_json::to_string(&schedule).unwrap();
+    assert_eq!(json, r#"{"days":[5,1,5],"closed":[7,6]}"#);
+    assert_eq!(serde_json::from_str::<Schedule>(&json).unwrap(), schedule);
+}
+
+#[test]
+fn weekday_sequences_reject_bad_numbers_and_set_duplicates() {
+    for (json, expected) in [
+        (
+            r#"{"days":[1,8],"closed":[]}"#,
+            "invalid element at index 1",
+        ),
+        (
+            r#"{"days":[],"closed":[6,7,6]}"#,
+            "invalid element at index 2: duplicate weekday 6",
+        ),
+    ] {
+        let err = serde_json::from_str::<Schedule>(json)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains(expected), "{}: {}", json, err);
+    }
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "chrono")]
+
+use chrono::TimeZone;
+use time::{Date, UtcOffset};
+use time_iso8601_serde::{
+    chrono_to_iso8601, datetime_from_iso8601, datetime_to_iso8601, iso8601_to_chrono,
+};
+
+#[test]
+fn chrono_and_time_round_trip_at_plus_two() {
+    let offset = chrono::FixedOffset::east_opt(2 * 3_600).unwrap();
+    let chrono = offset
+        .with_ymd_and_hms(2020, 1, 1, 12, 30, 15)
+        .single()
+        .unwrap()
+        + chrono::Duration::milliseconds(250);
+    let time = Date::try_from_ymd(2020, 1, 1)
+        .unwrap()
+        .try_with_hms_milli(12, 30, 15, 250)
+        .unwrap()
+        .assume_offset(UtcOffset::hours(2));
+
+    assert_eq!(
+        datetime_from_iso8601(chrono_to_iso8601(&chrono)).unwrap(),
+        time
+    );
+    assert_eq!(
+        iso8601_to_chrono(datetime_to_iso8601(time)).unwrap(),
+        chrono
+    );
+    assert_eq!(
+        iso8601_to_chrono(datetime_to_iso8601(time))
+            .unwrap()
+            .offset(),
+        &offset
+    );
+}
+
+#[test]
+fn negative_offsets_survive_the_bridge() {
+    let offset = chrono::FixedOffset::west_opt(5 * 3_600 + 30 * 60).unwrap();
+    let chrono = offset
+        .with_ymd_and_hms(2020, 1, 1, 0, 0, 0)
+        .single()
+        .unwrap();
+    let time = datetime_from_iso8601(chrono_to_iso8601(&chrono)).unwrap();
+    assert_eq!(time.offset(), UtcOffset::minutes(-330));
+    assert_eq!(
+        iso8601_to_chrono(datetime_to_iso8601(time)).unwrap(),
+        chrono
+    );
+}
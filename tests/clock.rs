@@ -0,0 +1,38 @@
+use serde::Deserialize;
+use time::{OffsetDateTime, UtcOffset};
+
+#[derive(Debug, Deserialize)]
+struct Entity {
+    #[serde(with = "time_iso8601_serde::datetime::assume_utc")]
+    assumed: OffsetDateTime,
+    #[serde(with = "time_iso8601_serde::datetime::lenient")]
+    lenient: OffsetDateTime,
+    #[serde(with = "time_iso8601_serde::datetime::lenient")]
+    date: OffsetDateTime,
+}
+
+const JSON: &str = r#"{
+    "assumed": "2020-01-01T12:00:00",
+    "lenient": "2020-01-01 12:00:00",
+    "date": "2020-01-01"
+}"#;
+
+// The time zone is set so that reading the local offset, if anything did, would give something
+// other than UTC. Parsing happens on several threads at once, where `time` refuses to look up
+// the local offset at all.
+#[test]
+fn missing_offsets_are_utc_whatever_the_local_time_zone() {
+    std::env::set_var("TZ", "Asia/Tokyo");
+    let threads = (0..8)
+        .map(|_| std::thread::spawn(|| serde_json::from_str::<Entity>(JSON).unwrap()))
+        .collect::<Vec<_>>();
+    for thread in threads {
+        let entity = thread.join().unwrap();
+        for at in [entity.assumed, entity.lenient, entity.date] {
+            assert_eq!(at.offset(), UtcOffset::UTC);
+        }
+        assert_eq!(entity.assumed.unix_timestamp(), 1_577_880_000);
+        assert_eq!(entity.lenient, entity.assumed);
+        assert_eq!(entity.date.unix_timestamp(), 1_577_836_800);
+    }
+}
@@ -0,0 +1,504 @@
+use serde::Deserialize;
+use time::{Date, Time, UtcOffset};
+use time_iso8601_serde::{
+    date_from_iso8601, date_from_iso8601_ref, date_to_iso8601, date_to_iso8601_as,
+    dates_from_iso8601, dates_to_iso8601, datetime_from_iso8601_ref, datetime_parts_from_iso8601,
+    datetime_parts_to_iso8601, datetime_to_iso8601, datetime_to_iso8601_parts,
+    datetimes_from_iso8601, datetimes_to_iso8601, days_in_month, duration_from_iso8601_ref,
+    iso8601_date_kind, iso_index_of, parse_iso8601_date, parse_iso8601_datetime,
+    parse_iso8601_time, time_from_iso8601, time_from_iso8601_checked, time_from_iso8601_ref,
+    time_to_iso8601, try_date_to_iso8601, try_datetime_to_iso8601, weekday_from_iso,
+    weekday_to_iso, DateKind, Iso8601SerdeError,
+};
+
+#[derive(Debug, Deserialize)]
+struct Day {
+    #[serde(with = "time_iso8601_serde::date")]
+    _on: Date,
+}
+
+fn time(tz_offset_hours: i32, tz_offset_minutes: i32) -> iso8601::Time {
+    iso8601::Time {
+        hour: 12,
+        minute: 0,
+        second: 0,
+        millisecond: 0,
+        tz_offset_hours,
+        tz_offset_minutes,
+    }
+}
+
+#[test]
+fn huge_offset_fields_are_errors_not_overflows() {
+    for (hours, minutes) in [
+        (1_000, 0),
+        (i32::MAX, 0),
+        (i32::MIN, -59),
+        (0, i32::MAX),
+        (24, 0),
+    ] {
+        let (result, offset) = time_from_iso8601(time(hours, minutes));
+        assert!(
+            matches!(result, Err(Iso8601SerdeError::OutOfRange { .. })),
+            "{} {}",
+            hours,
+            minutes
+        );
+        assert_eq!(offset, UtcOffset::UTC);
+        assert!(time_from_iso8601_checked(time(hours, minutes)).is_err());
+    }
+    let (result, offset) = time_from_iso8601(time(-23, -59));
+    assert!(result.is_ok());
+    assert_eq!(offset, UtcOffset::minutes(-(23 * 60 + 59)));
+}
+
+const NEW_YEAR: iso8601::DateTime = {
+    let date = match date_from_iso8601(iso8601::Date::YMD {
+        year: 2020,
+        month: 1,
+        day: 1,
+    }) {
+        Ok(date) => date,
+        Err(_) => panic!("2020-01-01 is a valid date"),
+    };
+    datetime_parts_to_iso8601(date.midnight(), UtcOffset::UTC)
+};
+
+#[test]
+fn datetime_parts_convert_in_const_context() {
+    let at = Date::try_from_ymd(2020, 1, 1).unwrap().midnight();
+    assert_eq!(NEW_YEAR, datetime_to_iso8601(at.assume_utc()));
+    assert_eq!(
+        datetime_parts_from_iso8601(NEW_YEAR).unwrap(),
+        (at, UtcOffset::UTC)
+    );
+}
+
+#[test]
+fn only_the_hour_field_carries_the_sign() {
+    let noon = Time::try_from_hms(12, 0, 0).unwrap();
+    for (minutes, hours_field, minutes_field, display) in [
+        (-330, -5, 30, "12:00:00.0+-5:30"),
+        (-30, 0, -30, "12:00:00.0+00:-30"),
+        (345, 5, 45, "12:00:00.0+05:45"),
+    ] {
+        let time = time_to_iso8601(noon, UtcOffset::minutes(minutes));
+        assert_eq!(
+            (time.tz_offset_hours, time.tz_offset_minutes),
+            (hours_field, minutes_field)
+        );
+        // `iso8601`'s own `Display` always writes a `+` before the signed hour field.
+        assert_eq!(time.to_string(), display);
+        assert_eq!(time_from_iso8601(time).1, UtcOffset::minutes(minutes));
+        assert_eq!(
+            time_from_iso8601_checked(time).unwrap().1,
+            UtcOffset::minutes(minutes)
+        );
+    }
+    // The `iso8601` parser signs both fields, which is read the same.
+    assert_eq!(time_from_iso8601(time(-5, -30)).1, UtcOffset::minutes(-330));
+    assert!(time_from_iso8601_checked(time(5, -30)).is_err());
+}
+
+#[test]
+fn week_date_with_weekday_zero_is_an_error() {
+    let err = date_from_iso8601(iso8601::Date::Week {
+        year: 2020,
+        ww: 1,
+        d: 0,
+    })
+    .unwrap_err();
+    assert_eq!(err.to_string(), "weekday must be in the range 1..=7, got 0");
+    assert!(serde_json::from_str::<Day>(r#"{"_on":"2020-W01-0"}"#).is_err());
+}
+
+#[test]
+fn millisecond_1000_is_an_error_naming_the_field() {
+    let crafted = iso8601::Time {
+        millisecond: 1000,
+        ..time(0, 0)
+    };
+    let (err, offset) = time_from_iso8601(crafted);
+    assert_eq!(
+        err.unwrap_err().to_string(),
+        "millisecond must be in the range 0..=999, got 1000"
+    );
+    assert_eq!(offset, UtcOffset::UTC);
+    assert!(time_from_iso8601(iso8601::Time {
+        millisecond: 999,
+        ..time(0, 0)
+    })
+    .0
+    .is_ok());
+}
+
+#[test]
+fn ordinal_days_outside_the_year_are_errors_naming_the_field() {
+    let ordinal = |year, ddd| date_from_iso8601(iso8601::Date::Ordinal { year, ddd });
+    assert_eq!(
+        ordinal(2020, 0).unwrap_err().to_string(),
+        "ordinal must be in the range 1..=366, got 0"
+    );
+    assert_eq!(
+        ordinal(2020, 367).unwrap_err().to_string(),
+        "ordinal must be in the range 1..=366, got 367"
+    );
+    assert_eq!(
+        ordinal(2021, 366).unwrap_err().to_string(),
+        "ordinal of a non-leap year must be in the range 1..=365, got 366"
+    );
+    assert_eq!(
+        ordinal(2020, 366).unwrap(),
+        Date::try_from_ymd(2020, 12, 31).unwrap()
+    );
+}
+
+#[test]
+fn february_29_is_checked_against_leap_years() {
+    let ymd = |year, month, day| date_from_iso8601(iso8601::Date::YMD { year, month, day });
+    assert_eq!(
+        ymd(2021, 2, 29).unwrap_err().to_string(),
+        "February 2021 has only 28 days, got day 29"
+    );
+    assert_eq!(
+        ymd(2020, 2, 29).unwrap(),
+        Date::try_from_ymd(2020, 2, 29).unwrap()
+    );
+    assert_eq!(
+        ymd(2020, 4, 31).unwrap_err().to_string(),
+        "April 2020 has only 30 days, got day 31"
+    );
+    assert_eq!(days_in_month(2020, 2), 29);
+    assert_eq!(days_in_month(1900, 2), 28);
+    assert_eq!(days_in_month(2000, 2), 29);
+    assert_eq!(days_in_month(2021, 12), 31);
+    assert_eq!(days_in_month(2021, 13), 0);
+}
+
+#[test]
+fn iso_index_of_matches_the_weekday_order() {
+    let mut weekday = time::Weekday::Monday;
+    for index in 0..7 {
+        assert_eq!(iso_index_of(weekday), index);
+        assert_eq!(weekday_from_iso(index as u8 + 1).unwrap(), weekday);
+        assert_eq!(weekday_to_iso(weekday), index as u8 + 1);
+        weekday = weekday.next();
+    }
+    assert_eq!(weekday, time::Weekday::Monday);
+}
+
+#[test]
+fn iso8601_parts_keep_the_sub_millisecond_remainder() {
+    for nanosecond in [0, 999_999, 123_456_789, 999_999_999] {
+        let at = Date::try_from_ymd(2020, 1, 1)
+            .unwrap()
+            .try_with_hms_nano(12, 0, 0, nanosecond)
+            .unwrap()
+            .assume_offset(UtcOffset::hours(9));
+        let (iso, remainder) = datetime_to_iso8601_parts(at);
+        assert!(remainder < 1_000_000);
+        assert_eq!(iso.time.millisecond * 1_000_000 + remainder, nanosecond);
+        let truncated = at - time::Duration::nanoseconds(remainder.into());
+        assert_eq!(iso, datetime_to_iso8601(truncated));
+    }
+}
+
+#[test]
+fn fractions_round_to_the_nearest_millisecond_and_carry() {
+    let at = |(year, month, day), nanosecond| {
+        Date::try_from_ymd(year, month, day)
+            .unwrap()
+            .try_with_hms_nano(23, 59, 59, nanosecond)
+            .unwrap()
+            .assume_utc()
+    };
+    for (input, expected) in [
+        (at((2020, 1, 1), 999_600_000), "2020-01-02T00:00:00.0+00:00"),
+        (
+            at((2020, 12, 31), 999_600_000),
+            "2021-01-01T00:00:00.0+00:00",
+        ),
+        (
+            at((2020, 2, 28), 999_500_000),
+            "2020-02-29T00:00:00.0+00:00",
+        ),
+        (
+            at((2020, 1, 1), 999_499_999),
+            "2020-01-01T23:59:59.999+00:00",
+        ),
+        (
+            at((2020, 1, 1), 123_456_789),
+            "2020-01-01T23:59:59.123+00:00",
+        ),
+    ] {
+        assert_eq!(datetime_to_iso8601(input).to_string(), expected);
+    }
+    for (input, expected) in [
+        ("2020-01-01T23:59:59.9996Z", "2020-01-02T00:00:00.0+00:00"),
+        ("2020-01-01T12:59:59.9996Z", "2020-01-01T13:00:00.0+00:00"),
+        ("2020-W53-5T23:59:59.9996Z", "2020-53-06T00:00:00.0+00:00"),
+        ("2020-366T23:59:59.9996Z", "2021-001T00:00:00.0+00:00"),
+        ("2020-01-01T23:59:60.9996Z", "2020-01-01T23:59:60.999+00:00"),
+    ] {
+        assert_eq!(
+            parse_iso8601_datetime(input).unwrap().to_string(),
+            expected,
+            "{}",
+            input
+        );
+    }
+    let time = |s| parse_iso8601_time(s).unwrap().to_string();
+    assert_eq!(time("12:00:00.9996Z"), "12:00:01.0+00:00");
+    assert_eq!(time("23:59:59.9996Z"), "23:59:59.999+00:00");
+    let last = Time::try_from_hms_nano(23, 59, 59, 999_999_999).unwrap();
+    assert_eq!(
+        time_to_iso8601(last, UtcOffset::UTC).to_string(),
+        "23:59:59.999+00:00"
+    );
+}
+
+#[test]
+fn try_conversions_reject_what_iso8601_cannot_read_back() {
+    let date = Date::try_from_ymd(10_000, 1, 1).unwrap();
+    assert!(matches!(
+        try_date_to_iso8601(date),
+        Err(Iso8601SerdeError::OutOfRange {
+            name: "year",
+            value: 10_000,
+            ..
+        })
+    ));
+    let date = Date::try_from_ymd(9_999, 12, 31).unwrap();
+    assert_eq!(try_date_to_iso8601(date).unwrap(), date_to_iso8601(date));
+
+    let datetime = date.try_with_hms(23, 0, 0).unwrap().assume_utc();
+    for (offset, expected) in [
+        (UtcOffset::seconds(99 * 3_600), "UTC offset in seconds"),
+        (UtcOffset::seconds(3_601), ""),
+        (UtcOffset::hours(1), "year"),
+    ] {
+        let err = try_datetime_to_iso8601(datetime.to_offset(offset)).unwrap_err();
+        match (err, expected) {
+            (Iso8601SerdeError::OutOfRange { name, .. }, _) => assert_eq!(name, expected),
+            (Iso8601SerdeError::SubMinuteOffset, "") => {}
+            (err, _) => panic!("{:?}: {}", offset, err),
+        }
+    }
+}
+
+#[test]
+fn by_reference_conversions_match_the_by_value_ones() {
+    let dates = [
+        iso8601::Date::YMD {
+            year: 2020,
+            month: 2,
+            day: 29,
+        },
+        iso8601::Date::Ordinal {
+            year: 2021,
+            ddd: 366,
+        },
+    ];
+    let converted = dates.iter().map(date_from_iso8601_ref).collect::<Vec<_>>();
+    assert_eq!(
+        converted[0].unwrap(),
+        Date::try_from_ymd(2020, 2, 29).unwrap()
+    );
+    assert!(converted[1].is_err());
+
+    let (result, offset) = time_from_iso8601_ref(&time(-5, 30));
+    assert_eq!(result.unwrap(), Time::try_from_hms(12, 0, 0).unwrap());
+    assert_eq!(offset, UtcOffset::minutes(-330));
+
+    let datetime = iso8601::DateTime {
+        date: dates[0],
+        time: time(0, 0),
+    };
+    assert_eq!(
+        datetime_from_iso8601_ref(&datetime).unwrap(),
+        Date::try_from_ymd(2020, 2, 29)
+            .unwrap()
+            .with_time(Time::try_from_hms(12, 0, 0).unwrap())
+            .assume_utc()
+    );
+
+    let duration = iso8601::Duration::Weeks(2);
+    assert_eq!(
+        duration_from_iso8601_ref(&duration).unwrap(),
+        time::Duration::weeks(2)
+    );
+}
+
+#[test]
+fn leap_seconds_are_clamped_to_the_instant_before_them() {
+    let leap = iso8601::Time {
+        hour: 23,
+        minute: 59,
+        second: 60,
+        millisecond: 500,
+        ..time(0, 0)
+    };
+    let (result, offset) = time_from_iso8601(leap);
+    assert_eq!(
+        result.unwrap(),
+        Time::try_from_hms_milli(23, 59, 59, 999).unwrap()
+    );
+    assert_eq!(offset, UtcOffset::UTC);
+
+    let at = time_iso8601_serde::parse_datetime("2016-12-31T23:59:60Z").unwrap();
+    assert_eq!(
+        at,
+        Date::try_from_ymd(2016, 12, 31)
+            .unwrap()
+            .try_with_hms_nano(23, 59, 59, 999_999_999)
+            .unwrap()
+            .assume_utc()
+    );
+    assert!(time_iso8601_serde::parse_datetime("2016-12-31T23:59:61Z").is_err());
+}
+
+#[test]
+fn slices_convert_both_ways() {
+    let dates = [
+        Date::try_from_ymd(2020, 1, 1).unwrap(),
+        Date::try_from_ymd(2020, 12, 31).unwrap(),
+    ];
+    assert_eq!(
+        dates_from_iso8601(&dates_to_iso8601(&dates)).unwrap(),
+        dates
+    );
+    let datetimes = dates
+        .iter()
+        .map(|date| date.midnight().assume_offset(UtcOffset::hours(-3)))
+        .collect::<Vec<_>>();
+    let converted = datetimes_from_iso8601(&datetimes_to_iso8601(&datetimes)).unwrap();
+    assert_eq!(converted, datetimes);
+    assert!(converted
+        .iter()
+        .all(|datetime| datetime.offset() == UtcOffset::hours(-3)));
+    assert!(dates_from_iso8601(&[]).unwrap().is_empty());
+}
+
+#[test]
+fn slice_conversions_report_the_index_of_the_first_failure() {
+    let mut dates = dates_to_iso8601(&[Date::try_from_ymd(2020, 1, 1).unwrap(); 3]);
+    dates[1] = iso8601::Date::YMD {
+        year: 2021,
+        month: 2,
+        day: 29,
+    };
+    dates[2] = iso8601::Date::YMD {
+        year: 2020,
+        month: 13,
+        day: 1,
+    };
+    let (index, err) = dates_from_iso8601(&dates).unwrap_err();
+    assert_eq!(index, 1);
+    assert!(matches!(err, Iso8601SerdeError::DayOfMonth { day: 29, .. }));
+
+    let datetimes = [iso8601::DateTime {
+        date: dates[2],
+        time: time(0, 0),
+    }];
+    assert_eq!(datetimes_from_iso8601(&datetimes).unwrap_err().0, 0);
+}
+
+#[test]
+fn date_kinds_survive_a_round_trip() {
+    let date = Date::try_from_ymd(2021, 1, 3).unwrap();
+    for (kind, expected) in [
+        (
+            DateKind::Ymd,
+            iso8601::Date::YMD {
+                year: 2021,
+                month: 1,
+                day: 3,
+            },
+        ),
+        (
+            DateKind::Week,
+            iso8601::Date::Week {
+                year: 2020,
+                ww: 53,
+                d: 7,
+            },
+        ),
+        (
+            DateKind::Ordinal,
+            iso8601::Date::Ordinal { year: 2021, ddd: 3 },
+        ),
+    ] {
+        let iso = date_to_iso8601_as(date, kind);
+        assert_eq!(iso8601_date_kind(&iso), kind);
+        assert_eq!(iso, expected);
+        assert_eq!(date_from_iso8601(iso).unwrap(), date);
+    }
+}
+
+#[test]
+fn date_kinds_are_reported_for_dates_that_do_not_convert() {
+    for (s, kind) in [
+        ("2021-02-29", DateKind::Ymd),
+        ("2021-W53-1", DateKind::Week),
+        ("2021-366", DateKind::Ordinal),
+    ] {
+        let iso = parse_iso8601_date(s).unwrap();
+        assert_eq!(iso8601_date_kind(&iso), kind, "{}", s);
+        assert!(date_from_iso8601(iso).is_err(), "{}", s);
+    }
+}
+
+#[test]
+fn parsed_iso8601_values_convert_back_to_themselves() {
+    let datetime = parse_iso8601_datetime("2020-01-01T12:30:15.250+09:00").unwrap();
+    assert_eq!(
+        datetime,
+        iso8601::DateTime {
+            date: iso8601::Date::YMD {
+                year: 2020,
+                month: 1,
+                day: 1,
+            },
+            time: iso8601::Time {
+                hour: 12,
+                minute: 30,
+                second: 15,
+                millisecond: 250,
+                tz_offset_hours: 9,
+                tz_offset_minutes: 0,
+            },
+        }
+    );
+    let converted = time_iso8601_serde::datetime_from_iso8601(datetime).unwrap();
+    assert_eq!(datetime_to_iso8601(converted), datetime);
+
+    let date = parse_iso8601_date("2020-W01-3").unwrap();
+    assert_eq!(
+        date_to_iso8601_as(date_from_iso8601(date).unwrap(), iso8601_date_kind(&date)),
+        date
+    );
+    let time = parse_iso8601_time("23:59:59+05:30").unwrap();
+    let (converted, offset) = time_from_iso8601_checked(time).unwrap();
+    assert_eq!(time_to_iso8601(converted, offset), time);
+}
+
+#[test]
+fn parse_iso8601_rejects_malformed_input_but_not_out_of_range_values() {
+    for (result, name) in [
+        (
+            parse_iso8601_datetime("2020-01-01 12:00:00Z").map(drop),
+            "datetime",
+        ),
+        (parse_iso8601_date("2020/01/01").map(drop), "date"),
+        (parse_iso8601_time("12h00").map(drop), "time"),
+    ] {
+        assert!(
+            matches!(result, Err(Iso8601SerdeError::Parse(parsed)) if parsed == name),
+            "{}: {:?}",
+            name,
+            result
+        );
+    }
+    let date = parse_iso8601_date("2021-02-29").unwrap();
+    assert!(date_from_iso8601(date).is_err());
+}
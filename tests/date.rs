@@ -0,0 +1,433 @@
+use serde::{Deserialize, Serialize};
+use time::Date;
+use time_iso8601_serde::{DateKind, PreservingDate};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Entity {
+    #[serde(with = "time_iso8601_serde::date")]
+    on: Date,
+}
+
+fn round_trip(on: Date) -> String {
+    let json = serde_json::to_string(&Entity { on }).unwrap();
+    assert_eq!(serde_json::from_str::<Entity>(&json).unwrap().on, on);
+    json
+}
+
+#[test]
+fn expanded_and_negative_years_round_trip() {
+    for (year, expected) in [
+        (-1, r#"{"on":"-0001-01-01"}"#),
+        (0, r#"{"on":"0000-01-01"}"#),
+        (10_000, r#"{"on":"+10000-01-01"}"#),
+    ] {
+        assert_eq!(
+            round_trip(Date::try_from_ymd(year, 1, 1).unwrap()),
+            expected
+        );
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Week {
+    #[serde(with = "time_iso8601_serde::date::week")]
+    on: Date,
+}
+
+#[test]
+fn week_53_is_accepted_only_in_long_years() {
+    let on = serde_json::from_str::<Entity>(r#"{"on":"2020-W53-5"}"#)
+        .unwrap()
+        .on;
+    assert_eq!(on, Date::try_from_ymd(2021, 1, 1).unwrap());
+    assert_eq!(
+        serde_json::to_string(&Week { on }).unwrap(),
+        r#"{"on":"2020-W53-5"}"#
+    );
+    assert!(serde_json::from_str::<Entity>(r#"{"on":"2021-W53-1"}"#).is_err());
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct EpochDays {
+    #[serde(with = "time_iso8601_serde::date::epoch_days")]
+    on: Date,
+}
+
+#[test]
+fn epoch_days_round_trip() {
+    for ((year, month, day), days) in [
+        ((1970, 1, 1), 0),
+        ((1969, 12, 31), -1),
+        ((2020, 1, 1), 18_262),
+        ((-100_000, 1, 1), -37_243_778),
+        ((100_000, 12, 31), 35_805_087),
+    ] {
+        let on = Date::try_from_ymd(year, month, day).unwrap();
+        let json = serde_json::to_string(&EpochDays { on }).unwrap();
+        assert_eq!(json, format!(r#"{{"on":{}}}"#, days));
+        assert_eq!(serde_json::from_str::<EpochDays>(&json).unwrap().on, on);
+        let bincode = bincode::serialize(&EpochDays { on }).unwrap();
+        assert_eq!(bincode::deserialize::<EpochDays>(&bincode).unwrap().on, on);
+    }
+    assert!(serde_json::from_str::<EpochDays>(r#"{"on":35805088}"#).is_err());
+    assert!(serde_json::from_str::<EpochDays>(r#"{"on":-2147483648}"#).is_err());
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Nullable {
+    #[serde(with = "time_iso8601_serde::date::nullable_sentinel")]
+    on: Option<Date>,
+}
+
+#[test]
+fn zero_sentinels_are_none() {
+    for sentinel in [
+        r#"{"on":"0000-00-00"}"#,
+        r#"{"on":"0000-00-00T00:00:00"}"#,
+        r#"{"on":"0000-00-00 00:00:00"}"#,
+        r#"{"on":null}"#,
+    ] {
+        assert_eq!(serde_json::from_str::<Nullable>(sentinel).unwrap().on, None);
+    }
+    assert_eq!(
+        serde_json::from_str::<Nullable>(r#"{"on":"2020-01-01"}"#)
+            .unwrap()
+            .on,
+        Some(Date::try_from_ymd(2020, 1, 1).unwrap())
+    );
+    assert!(serde_json::from_str::<Nullable>(r#"{"on":"0000-00-01"}"#).is_err());
+    assert_eq!(
+        serde_json::to_string(&Nullable { on: None }).unwrap(),
+        r#"{"on":null}"#
+    );
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Basic {
+    #[serde(with = "time_iso8601_serde::date::basic")]
+    on: Date,
+}
+
+#[test]
+fn basic_and_extended_forms_round_trip_to_the_same_date() {
+    for (year, basic, extended) in [
+        (2020, r#"{"on":"20200101"}"#, r#"{"on":"2020-01-01"}"#),
+        (0, r#"{"on":"00000101"}"#, r#"{"on":"0000-01-01"}"#),
+        (9999, r#"{"on":"99990101"}"#, r#"{"on":"9999-01-01"}"#),
+    ] {
+        let on = Date::try_from_ymd(year, 1, 1).unwrap();
+        assert_eq!(serde_json::to_string(&Basic { on }).unwrap(), basic);
+        assert_eq!(serde_json::from_str::<Basic>(basic).unwrap().on, on);
+        assert_eq!(serde_json::from_str::<Basic>(extended).unwrap().on, on);
+        assert_eq!(serde_json::from_str::<Entity>(basic).unwrap().on, on);
+    }
+}
+
+#[test]
+fn basic_rejects_years_it_cannot_write_unambiguously() {
+    for year in [-1, 10_000] {
+        let on = Date::try_from_ymd(year, 1, 1).unwrap();
+        assert!(serde_json::to_string(&Basic { on }).is_err());
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Ordinal {
+    #[serde(with = "time_iso8601_serde::date::ordinal")]
+    on: Date,
+}
+
+#[test]
+fn day_366_is_accepted_only_in_leap_years() {
+    let on = Date::try_from_ymd(2020, 12, 31).unwrap();
+    assert_eq!(
+        serde_json::to_string(&Ordinal { on }).unwrap(),
+        r#"{"on":"2020-366"}"#
+    );
+    assert_eq!(
+        serde_json::from_str::<Ordinal>(r#"{"on":"2020-366"}"#)
+            .unwrap()
+            .on,
+        on
+    );
+    assert!(serde_json::from_str::<Ordinal>(r#"{"on":"2021-366"}"#).is_err());
+    assert!(serde_json::from_str::<Ordinal>(r#"{"on":"2020-000"}"#).is_err());
+}
+
+#[test]
+fn week_dates_use_the_iso_year() {
+    for ((year, month, day), expected) in [
+        ((2021, 1, 1), r#"{"on":"2020-W53-5"}"#),
+        ((2019, 12, 30), r#"{"on":"2020-W01-1"}"#),
+        ((2020, 1, 1), r#"{"on":"2020-W01-3"}"#),
+    ] {
+        let on = Date::try_from_ymd(year, month, day).unwrap();
+        let json = serde_json::to_string(&Week { on }).unwrap();
+        assert_eq!(json, expected);
+        assert_eq!(serde_json::from_str::<Week>(&json).unwrap().on, on);
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct DateOrDatetime {
+    #[serde(with = "time_iso8601_serde::date::from_datetime_or_date")]
+    on: Date,
+}
+
+#[test]
+fn from_datetime_or_date_keeps_the_date_of_either_input() {
+    let on = |s: &str| {
+        serde_json::from_str::<DateOrDatetime>(&format!(r#"{{"on":"{}"}}"#, s))
+            .map(|entity| entity.on)
+    };
+    let expected = Date::try_from_ymd(2020, 1, 1).unwrap();
+    assert_eq!(on("2020-01-01").unwrap(), expected);
+    assert_eq!(on("2020-01-01T12:00:00Z").unwrap(), expected);
+    assert_eq!(on("2020-01-01T23:00:00-05:00").unwrap(), expected);
+    assert_eq!(
+        serde_json::to_string(&DateOrDatetime { on: expected }).unwrap(),
+        r#"{"on":"2020-01-01"}"#
+    );
+    assert!(on("2020-01-01T25:00:00Z").is_err());
+    assert!(on("2020-01-32").is_err());
+}
+
+#[test]
+fn preserving_date_writes_back_the_form_it_was_read_in() {
+    let on = Date::try_from_ymd(2020, 1, 1).unwrap();
+    for (s, kind) in [
+        ("2020-01-01", DateKind::Ymd),
+        ("2020-W01-3", DateKind::Week),
+        ("2020-001", DateKind::Ordinal),
+    ] {
+        let json = format!("\"{}\"", s);
+        let date = serde_json::from_str::<PreservingDate>(&json).unwrap();
+        assert_eq!(date.date(), on, "{}", s);
+        assert_eq!(date.kind(), kind, "{}", s);
+        assert_eq!(serde_json::to_string(&date).unwrap(), json);
+    }
+    assert_eq!(
+        serde_json::to_string(&PreservingDate::ordinal(on)).unwrap(),
+        r#""2020-001""#
+    );
+}
+
+#[test]
+fn preserving_date_rejects_invalid_dates_in_any_form() {
+    for s in [
+        "2021-02-29",
+        "2021-W53-1",
+        "2021-366",
+        "2020-01-01T00:00:00Z",
+    ] {
+        let json = format!("\"{}\"", s);
+        assert!(
+            serde_json::from_str::<PreservingDate>(&json).is_err(),
+            "{}",
+            s
+        );
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Nested {
+    #[serde(with = "time_iso8601_serde::date::optional_vec")]
+    holidays: Option<Vec<Date>>,
+    #[serde(with = "time_iso8601_serde::date::vec_optional")]
+    slots: Vec<Option<Date>>,
+}
+
+#[test]
+fn nested_date_containers_round_trip() {
+    let on = Date::try_from_ymd(2020, 1, 1).unwrap();
+    for (nested, json) in [
+        (
+            Nested {
+                holidays: Some(vec![on, on.next_day()]),
+                slots: vec![None, Some(on)],
+            },
+            r#"{"holidays":["2020-01-01","2020-01-02"],"slots":[null,"2020-01-01"]}"#,
+        ),
+        (
+            Nested {
+                holidays: None,
+                slots: vec![],
+            },
+            r#"{"holidays":null,"slots":[]}"#,
+        ),
+    ] {
+        assert_eq!(serde_json::to_string(&nested).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Nested>(json).unwrap(), nested);
+    }
+}
+
+#[test]
+fn nested_date_containers_reject_invalid_elements() {
+    for json in [
+        r#"{"holidays":["2020-01-01","2020-02-30"],"slots":[]}"#,
+        r#"{"holidays":[null],"slots":[]}"#,
+        r#"{"holidays":null,"slots":[null,"2020-13-01"]}"#,
+        r#"{"holidays":"2020-01-01","slots":[]}"#,
+    ] {
+        assert!(serde_json::from_str::<Nested>(json).is_err(), "{}", json);
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Reduced {
+    #[serde(with = "time_iso8601_serde::date::reduced")]
+    on: Date,
+}
+
+#[test]
+fn reduced_dates_are_read_as_the_first_day() {
+    for (s, expected) in [
+        ("2020", (2020, 1, 1)),
+        ("2020-07", (2020, 7, 1)),
+        ("2020-07-15", (2020, 7, 15)),
+    ] {
+        let json = format!(r#"{{"on":"{}"}}"#, s);
+        let on = serde_json::from_str::<Reduced>(&json).unwrap().on;
+        let (year, month, day) = expected;
+        assert_eq!(on, Date::try_from_ymd(year, month, day).unwrap(), "{}", s);
+        let written = serde_json::to_string(&Reduced { on }).unwrap();
+        assert_eq!(serde_json::from_str::<Reduced>(&written).unwrap().on, on);
+    }
+    assert_eq!(
+        serde_json::to_string(&Reduced {
+            on: Date::try_from_ymd(2020, 7, 1).unwrap()
+        })
+        .unwrap(),
+        r#"{"on":"2020-07-01"}"#
+    );
+}
+
+#[test]
+fn reduced_dates_reject_basic_and_out_of_range_forms() {
+    for s in ["202007", "2020-13", "2020-00", "20", "2020-7", "２０２０"] {
+        let json = format!(r#"{{"on":"{}"}}"#, s);
+        assert!(serde_json::from_str::<Reduced>(&json).is_err(), "{}", s);
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Ymd {
+    #[serde(with = "time_iso8601_serde::date::ymd_array")]
+    on: Date,
+}
+
+#[test]
+fn ymd_arrays_round_trip_in_json_and_bincode() {
+    let on = Date::try_from_ymd(-1, 12, 31).unwrap();
+    let json = serde_json::to_string(&Ymd { on }).unwrap();
+    assert_eq!(json, r#"{"on":[-1,12,31]}"#);
+    assert_eq!(serde_json::from_str::<Ymd>(&json).unwrap().on, on);
+    let bincode = bincode::serialize(&Ymd { on }).unwrap();
+    assert_eq!(bincode::deserialize::<Ymd>(&bincode).unwrap().on, on);
+}
+
+#[test]
+fn ymd_arrays_reject_invalid_dates_and_lengths() {
+    for json in [
+        r#"{"on":[2021,2,29]}"#,
+        r#"{"on":[2020,13,1]}"#,
+        r#"{"on":[2020,1]}"#,
+        r#"{"on":[2020,1,1,0]}"#,
+        r#"{"on":"2020-01-01"}"#,
+    ] {
+        assert!(serde_json::from_str::<Ymd>(json).is_err(), "{}", json);
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Stay {
+    #[serde(with = "time_iso8601_serde::date_range")]
+    nights: std::ops::Range<Date>,
+}
+
+#[test]
+fn date_ranges_round_trip_as_intervals() {
+    let start = Date::try_from_ymd(2020, 1, 1).unwrap();
+    for (nights, json) in [
+        (
+            start..Date::try_from_ymd(2020, 1, 31).unwrap(),
+            r#"{"nights":"2020-01-01/2020-01-31"}"#,
+        ),
+        (start..start, r#"{"nights":"2020-01-01/2020-01-01"}"#),
+    ] {
+        assert_eq!(
+            serde_json::to_string(&Stay {
+                nights: nights.clone()
+            })
+            .unwrap(),
+            json
+        );
+        assert_eq!(serde_json::from_str::<Stay>(json).unwrap().nights, nights);
+    }
+}
+
+#[test]
+fn date_ranges_reject_reversed_and_malformed_intervals() {
+    let start = Date::try_from_ymd(2020, 1, 31).unwrap();
+    let reversed = serde_json::to_string(&Stay {
+        nights: start..Date::try_from_ymd(2020, 1, 1).unwrap(),
+    });
+    assert!(reversed.is_err());
+    for s in [
+        "2020-01-31/2020-01-01",
+        "2020-01-01",
+        "2020-01-01/2020-02-30",
+        "2020-01-01/P1D",
+    ] {
+        let json = format!(r#"{{"nights":"{}"}}"#, s);
+        assert!(serde_json::from_str::<Stay>(&json).is_err(), "{}", s);
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct WeekStruct {
+    #[serde(with = "time_iso8601_serde::date::week_struct")]
+    on: Date,
+}
+
+#[test]
+fn week_structs_use_the_week_numbering_year() {
+    for (on, json) in [
+        (
+            Date::try_from_ymd(2021, 1, 1).unwrap(),
+            r#"{"on":{"year":2020,"week":53,"weekday":5}}"#,
+        ),
+        (
+            Date::try_from_ymd(2020, 1, 1).unwrap(),
+            r#"{"on":{"year":2020,"week":1,"weekday":3}}"#,
+        ),
+    ] {
+        assert_eq!(serde_json::to_string(&WeekStruct { on }).unwrap(), json);
+        assert_eq!(serde_json::from_str::<WeekStruct>(json).unwrap().on, on);
+    }
+    let bincode = bincode::serialize(&WeekStruct {
+        on: Date::try_from_ymd(2021, 1, 1).unwrap(),
+    })
+    .unwrap();
+    assert_eq!(
+        bincode::deserialize::<WeekStruct>(&bincode).unwrap().on,
+        Date::try_from_ymd(2021, 1, 1).unwrap()
+    );
+}
+
+#[test]
+fn week_structs_reject_weeks_and_weekdays_out_of_range() {
+    for fields in [
+        r#"{"year":2021,"week":53,"weekday":1}"#,
+        r#"{"year":2020,"week":0,"weekday":1}"#,
+        r#"{"year":2020,"week":1,"weekday":8}"#,
+        r#"{"year":2020,"week":1}"#,
+    ] {
+        let json = format!(r#"{{"on":{}}}"#, fields);
+        assert!(
+            serde_json::from_str::<WeekStruct>(&json).is_err(),
+            "{}",
+            fields
+        );
+    }
+}
@@ -0,0 +1,1016 @@
+use serde::{Deserialize, Serialize};
+use time::{Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Entity {
+    #[serde(with = "time_iso8601_serde::datetime")]
+    at: OffsetDateTime,
+}
+
+fn datetime(year: i32, month: u8, day: u8, hour: u8, offset: UtcOffset) -> OffsetDateTime {
+    PrimitiveDateTime::new(
+        Date::try_from_ymd(year, month, day).unwrap(),
+        Time::try_from_hms(hour, 0, 0).unwrap(),
+    )
+    .assume_offset(offset)
+}
+
+fn from_json(s: &str) -> Result<OffsetDateTime, serde_json::Error> {
+    serde_json::from_str::<Entity>(&format!(r#"{{"at":"{}"}}"#, s)).map(|entity| entity.at)
+}
+
+fn round_trip(at: OffsetDateTime) -> String {
+    let json = serde_json::to_string(&Entity { at }).unwrap();
+    assert_eq!(serde_json::from_str::<Entity>(&json).unwrap().at, at);
+    json
+}
+
+#[test]
+fn extremes_round_trip() {
+    let min = Date::try_from_ymd(-100_000, 1, 1).unwrap().midnight();
+    let max = Date::try_from_ymd(100_000, 12, 31)
+        .unwrap()
+        .try_with_hms_nano(23, 59, 59, 999_999_999)
+        .unwrap();
+    assert_eq!(
+        round_trip(min.assume_utc()),
+        r#"{"at":"-100000-01-01T00:00:00.000+00:00"}"#
+    );
+    assert_eq!(
+        round_trip(max.assume_utc()),
+        r#"{"at":"+100000-12-31T23:59:59.999999999+00:00"}"#
+    );
+}
+
+#[test]
+fn extremes_out_of_range_in_utc_are_errors() {
+    for s in [
+        "-100000-01-01T00:00:00+01:00",
+        "+100000-12-31T23:00:00-01:00",
+    ] {
+        let err = from_json(s).unwrap_err().to_string();
+        assert!(err.contains("year in UTC"), "{}: {}", s, err);
+    }
+    assert_eq!(
+        from_json("-100000-01-01T01:00:00+01:00").unwrap(),
+        datetime(-100_000, 1, 1, 0, UtcOffset::UTC)
+    );
+}
+
+#[test]
+fn expanded_and_negative_years_round_trip() {
+    for (year, expected) in [
+        (-1, r#"{"at":"-0001-01-01T00:00:00.000+00:00"}"#),
+        (0, r#"{"at":"0000-01-01T00:00:00.000+00:00"}"#),
+        (10_000, r#"{"at":"+10000-01-01T00:00:00.000+00:00"}"#),
+    ] {
+        assert_eq!(
+            round_trip(datetime(year, 1, 1, 0, UtcOffset::UTC)),
+            expected
+        );
+    }
+    assert_eq!(
+        round_trip(datetime(10_000, 1, 1, 0, UtcOffset::hours(9))),
+        r#"{"at":"+10000-01-01T00:00:00.000+09:00"}"#
+    );
+    assert_eq!(
+        round_trip(datetime(-1, 12, 31, 23, UtcOffset::minutes(-330))),
+        r#"{"at":"-0001-12-31T23:00:00.000-05:30"}"#
+    );
+    assert_eq!(
+        round_trip(datetime(-100_000, 1, 1, 12, UtcOffset::hours(5))),
+        r#"{"at":"-100000-01-01T12:00:00.000+05:00"}"#
+    );
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Variants {
+    #[serde(with = "time_iso8601_serde::datetime")]
+    plain: OffsetDateTime,
+    #[serde(with = "time_iso8601_serde::datetime::zulu")]
+    zulu: OffsetDateTime,
+    #[serde(with = "time_iso8601_serde::datetime::optional")]
+    optional: Option<OffsetDateTime>,
+    #[serde(with = "time_iso8601_serde::datetime::vec")]
+    vec: Vec<OffsetDateTime>,
+    #[serde(with = "time_iso8601_serde::datetime::fixed_millis")]
+    fixed_millis: OffsetDateTime,
+}
+
+fn precise() -> OffsetDateTime {
+    PrimitiveDateTime::new(
+        Date::try_from_ymd(2020, 1, 2).unwrap(),
+        Time::try_from_hms_nano(3, 4, 5, 123_456_789).unwrap(),
+    )
+    .assume_offset(UtcOffset::minutes(330))
+}
+
+#[test]
+fn json_and_bincode_round_trips_are_lossless() {
+    let at = precise();
+    let variants = Variants {
+        plain: at,
+        zulu: at,
+        optional: Some(at),
+        vec: vec![at],
+        fixed_millis: at,
+    };
+    let json = serde_json::to_string(&variants).unwrap();
+    let from_json = serde_json::from_str::<Variants>(&json).unwrap();
+    assert_eq!(from_json.fixed_millis.nanosecond(), 123_000_000);
+    let bincode = bincode::serialize(&variants).unwrap();
+    let from_bincode = bincode::deserialize::<Variants>(&bincode).unwrap();
+    assert_eq!(from_bincode.fixed_millis, at);
+    for variants in [from_json, from_bincode] {
+        for at in [
+            variants.plain,
+            variants.zulu,
+            variants.optional.unwrap(),
+            variants.vec[0],
+        ] {
+            assert_eq!(at, precise());
+            assert_eq!(at.offset(), UtcOffset::minutes(330));
+            assert_eq!(at.nanosecond(), 123_456_789);
+        }
+    }
+}
+
+#[test]
+fn bincode_writes_timestamp_and_offset_pairs() {
+    let at = precise();
+    let pair = bincode::serialize(&Entity { at }).unwrap();
+    assert_eq!(
+        bincode::deserialize::<(i128, i32)>(&pair).unwrap(),
+        (at.unix_timestamp_nanos(), 19_800)
+    );
+    assert_eq!(
+        serde_json::to_string(&Entity { at }).unwrap(),
+        r#"{"at":"2020-01-02T03:04:05.123456789+05:30"}"#
+    );
+
+    let out_of_range = bincode::serialize(&(at.unix_timestamp_nanos(), 99 * 3_600)).unwrap();
+    let err = bincode::deserialize::<Entity>(&out_of_range).unwrap_err();
+    assert!(err.to_string().contains("UTC offset in seconds"), "{}", err);
+    let string = bincode::serialize("2020-01-02T03:04:05Z").unwrap();
+    assert!(bincode::deserialize::<Entity>(&string).is_err());
+}
+
+#[test]
+fn week_53_datetimes_are_accepted_only_in_long_years() {
+    assert_eq!(
+        from_json("2020-W53-5T00:00:00Z").unwrap(),
+        datetime(2021, 1, 1, 0, UtcOffset::UTC)
+    );
+    assert_eq!(
+        from_json("2020W535T120000+0900").unwrap(),
+        datetime(2021, 1, 1, 12, UtcOffset::hours(9))
+    );
+    assert!(from_json("2021-W53-1T00:00:00Z").is_err());
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct AssumeUtc {
+    #[serde(with = "time_iso8601_serde::datetime::assume_utc")]
+    at: OffsetDateTime,
+}
+
+#[test]
+fn missing_offset_is_rejected_unless_assumed_utc() {
+    let err = from_json("2020-01-01T12:00:00").unwrap_err().to_string();
+    assert!(err.contains("datetime has no UTC offset"), "{}", err);
+
+    for (s, offset) in [
+        ("2020-01-01T12:00:00", UtcOffset::UTC),
+        ("2020-01-01T12:00:00Z", UtcOffset::UTC),
+        ("2020-01-01T12:00:00+09:00", UtcOffset::hours(9)),
+    ] {
+        let at = serde_json::from_str::<AssumeUtc>(&format!(r#"{{"at":"{}"}}"#, s))
+            .unwrap()
+            .at;
+        assert_eq!(at, datetime(2020, 1, 1, 12, offset), "{}", s);
+        assert_eq!(at.offset(), offset, "{}", s);
+    }
+}
+
+#[test]
+fn conflicting_zone_designators_are_rejected() {
+    for s in ["2020-01-01T00:00:00Z+02:00", "2020-01-01T00:00:00Z-0200"] {
+        let err = from_json(s).unwrap_err().to_string();
+        assert!(
+            err.contains("both a Z and a numeric UTC offset"),
+            "{}: {}",
+            s,
+            err
+        );
+    }
+}
+
+#[test]
+fn fractions_are_written_in_groups_of_three_digits() {
+    let at = datetime(2020, 1, 1, 0, UtcOffset::UTC);
+    for (nanosecond, expected) in [
+        (0, r#"{"at":"2020-01-01T00:00:00.000+00:00"}"#),
+        (500_000_000, r#"{"at":"2020-01-01T00:00:00.500+00:00"}"#),
+        (123_456_000, r#"{"at":"2020-01-01T00:00:00.123456+00:00"}"#),
+        (
+            123_456_789,
+            r#"{"at":"2020-01-01T00:00:00.123456789+00:00"}"#,
+        ),
+    ] {
+        let at = at + time::Duration::nanoseconds(nanosecond);
+        assert_eq!(round_trip(at), expected);
+    }
+    assert_eq!(from_json("2020-01-01T00:00:00.0+00:00").unwrap(), at);
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Lenient {
+    #[serde(with = "time_iso8601_serde::datetime::lenient")]
+    at: OffsetDateTime,
+}
+
+#[test]
+fn lenient_reads_a_bare_date_as_midnight_utc() {
+    let lenient = |s: &str| {
+        serde_json::from_str::<Lenient>(&format!(r#"{{"at":"{}"}}"#, s)).map(|entity| entity.at)
+    };
+    assert_eq!(
+        lenient("2020-01-01").unwrap(),
+        datetime(2020, 1, 1, 0, UtcOffset::UTC)
+    );
+    assert_eq!(
+        lenient("2020-01-01T12:00:00+09:00").unwrap(),
+        datetime(2020, 1, 1, 12, UtcOffset::hours(9))
+    );
+    assert!(lenient("2020-02-30").is_err());
+    assert!(from_json("2020-01-01").is_err());
+}
+
+#[test]
+fn lenient_normalizes_messy_inputs() {
+    for (messy, expected) in [
+        ("2020-1-1T9:05:00Z", "2020-01-01T09:05:00.000+00:00"),
+        ("2020-01-01t12:00:00z", "2020-01-01T12:00:00.000+00:00"),
+        ("2020-01-01 12:00:00+09:00", "2020-01-01T12:00:00.000+09:00"),
+        ("2020-1-01 7:5", "2020-01-01T07:05:00.000+00:00"),
+        (
+            "2020-12-3t23:59:59.5-05:00",
+            "2020-12-03T23:59:59.500-05:00",
+        ),
+        ("2020-01-01 12:00:00", "2020-01-01T12:00:00.000+00:00"),
+        ("2020-2-29", "2020-02-29T00:00:00.000+00:00"),
+    ] {
+        let json = format!(r#"{{"at":"{}"}}"#, messy);
+        let at = serde_json::from_str::<Lenient>(&json)
+            .unwrap_or_else(|err| panic!("{}: {}", messy, err))
+            .at;
+        assert_eq!(
+            serde_json::to_string(&Lenient { at }).unwrap(),
+            format!(r#"{{"at":"{}"}}"#, expected),
+            "{}",
+            messy
+        );
+    }
+    for messy in [
+        "2020-1-1T09:05:00Y",
+        "20-01-01T00:00:00Z",
+        "2020-001-01",
+        "2020-01-01T1:2:3:4Z",
+        "2020-01-01Té:00Z",
+        "",
+    ] {
+        let json = format!(r#"{{"at":"{}"}}"#, messy);
+        assert!(serde_json::from_str::<Lenient>(&json).is_err(), "{}", messy);
+    }
+}
+
+#[test]
+fn in_offset_changes_only_the_written_offset() {
+    let at = datetime(2020, 1, 1, 0, UtcOffset::UTC);
+    let plus_two = UtcOffset::hours(2);
+    let iso = time_iso8601_serde::datetime_to_iso8601_in_offset(at, plus_two);
+    assert_eq!(iso.to_string(), "2020-01-01T02:00:00.0+02:00");
+    assert_eq!(time_iso8601_serde::datetime_from_iso8601(iso).unwrap(), at);
+    assert_eq!(
+        serde_json::to_string(&time_iso8601_serde::datetime::InOffset(&at, plus_two)).unwrap(),
+        r#""2020-01-01T02:00:00.000+02:00""#
+    );
+    assert_eq!(at.offset(), UtcOffset::UTC);
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct UtcNormalized {
+    #[serde(with = "time_iso8601_serde::datetime::utc_normalized")]
+    at: OffsetDateTime,
+}
+
+#[test]
+fn utc_normalized_keeps_the_instant_and_drops_the_offset() {
+    let at = serde_json::from_str::<UtcNormalized>(r#"{"at":"2020-01-01T02:00:00+02:00"}"#)
+        .unwrap()
+        .at;
+    assert_eq!(at, datetime(2020, 1, 1, 0, UtcOffset::UTC));
+    assert_eq!(at.offset(), UtcOffset::UTC);
+    assert_eq!(at.hour(), 0);
+    let at = datetime(2020, 1, 1, 9, UtcOffset::hours(9));
+    assert_eq!(
+        serde_json::to_string(&UtcNormalized { at }).unwrap(),
+        r#"{"at":"2020-01-01T00:00:00.000Z"}"#
+    );
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Rfc3339 {
+    #[serde(with = "time_iso8601_serde::datetime::rfc3339_strict")]
+    at: OffsetDateTime,
+}
+
+#[test]
+fn rfc3339_strict_rejects_other_iso_forms() {
+    let strict = |s: &str| serde_json::from_str::<Rfc3339>(&format!(r#"{{"at":"{}"}}"#, s));
+    assert_eq!(
+        strict("2020-01-01T00:00:00Z").unwrap().at,
+        datetime(2020, 1, 1, 0, UtcOffset::UTC)
+    );
+    assert!(strict("2020-01-01T00:00:00.5+09:00").is_ok());
+    for s in [
+        "2020-W01-1T00:00:00Z",
+        "2020-001T00:00:00Z",
+        "20200101T000000Z",
+        "2020-01-01T00:00Z",
+    ] {
+        assert!(strict(s).is_err(), "{}", s);
+        assert!(from_json(s).is_ok(), "{}", s);
+    }
+    assert!(strict("2020-01-01T00:00:00").is_err());
+}
+
+#[test]
+fn end_of_day_rolls_over_into_the_next_day() {
+    assert_eq!(
+        from_json("2020-01-01T24:00:00Z").unwrap(),
+        datetime(2020, 1, 2, 0, UtcOffset::UTC)
+    );
+    assert_eq!(
+        from_json("2020-12-31T24:00:00+09:00").unwrap(),
+        datetime(2021, 1, 1, 0, UtcOffset::hours(9))
+    );
+    assert!(from_json("2020-01-01T24:00:01Z").is_err());
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Fractions {
+    #[serde(with = "time_iso8601_serde::datetime::fixed_millis")]
+    millis: OffsetDateTime,
+    #[serde(with = "time_iso8601_serde::datetime::fixed_micros")]
+    micros: OffsetDateTime,
+    #[serde(with = "time_iso8601_serde::datetime::trimmed")]
+    trimmed: OffsetDateTime,
+}
+
+#[test]
+fn fraction_variants_write_their_digits() {
+    for (nanosecond, millis, micros, trimmed) in [
+        (0, ".000", ".000000", ""),
+        (500_000_000, ".500", ".500000", ".5"),
+        (123_456_000, ".123", ".123456", ".123456"),
+    ] {
+        let at = datetime(2020, 1, 1, 0, UtcOffset::UTC) + time::Duration::nanoseconds(nanosecond);
+        let json = serde_json::to_string(&Fractions {
+            millis: at,
+            micros: at,
+            trimmed: at,
+        })
+        .unwrap();
+        assert_eq!(
+            json,
+            format!(
+                r#"{{"millis":"2020-01-01T00:00:00{}+00:00","micros":"2020-01-01T00:00:00{}+00:00","trimmed":"2020-01-01T00:00:00{}+00:00"}}"#,
+                millis, micros, trimmed
+            )
+        );
+        let read = serde_json::from_str::<Fractions>(&json).unwrap();
+        assert_eq!((read.micros, read.trimmed), (at, at));
+        assert_eq!(
+            read.millis,
+            at - time::Duration::nanoseconds(nanosecond % 1_000_000)
+        );
+    }
+}
+
+#[test]
+fn half_hour_offsets_keep_their_wall_clock_time() {
+    let ist = UtcOffset::minutes(330);
+    let at = datetime(2020, 1, 1, 12, ist) + time::Duration::minutes(30);
+    assert_eq!(round_trip(at), r#"{"at":"2020-01-01T12:30:00.000+05:30"}"#);
+    assert_eq!(
+        from_json("2020-01-01T12:30:00+05:30").unwrap().offset(),
+        ist
+    );
+    assert_eq!(
+        time_iso8601_serde::datetime_to_iso8601(at).to_string(),
+        "2020-01-01T12:30:00.0+05:30"
+    );
+    let at = datetime(2020, 1, 1, 12, UtcOffset::minutes(-330));
+    assert_eq!(round_trip(at), r#"{"at":"2020-01-01T12:00:00.000-05:30"}"#);
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Padded {
+    #[serde(with = "time_iso8601_serde::datetime::trim_whitespace")]
+    at: OffsetDateTime,
+    #[serde(with = "time_iso8601_serde::date::trim_whitespace")]
+    on: Date,
+    #[serde(with = "time_iso8601_serde::time_offset::trim_whitespace")]
+    time: (Time, UtcOffset),
+}
+
+#[test]
+fn padded_input_is_trimmed() {
+    let padded = serde_json::from_str::<Padded>(
+        r#"{"at":" 2020-01-01T00:00:00Z ","on":"\t2020-01-01\n","time":"  12:00:00+09:00"}"#,
+    )
+    .unwrap();
+    assert_eq!(padded.at, datetime(2020, 1, 1, 0, UtcOffset::UTC));
+    assert_eq!(padded.on, Date::try_from_ymd(2020, 1, 1).unwrap());
+    assert_eq!(
+        padded.time,
+        (Time::try_from_hms(12, 0, 0).unwrap(), UtcOffset::hours(9))
+    );
+    assert!(from_json(" 2020-01-01T00:00:00Z ").is_err());
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct EmptyAsNone {
+    #[serde(with = "time_iso8601_serde::datetime::empty_as_none")]
+    at: Option<OffsetDateTime>,
+}
+
+#[test]
+fn empty_as_none_reads_only_empty_strings_as_none() {
+    let read = |json: &str| serde_json::from_str::<EmptyAsNone>(json).map(|entity| entity.at);
+    assert_eq!(read(r#"{"at":""}"#).unwrap(), None);
+    assert_eq!(read(r#"{"at":null}"#).unwrap(), None);
+    assert_eq!(
+        read(r#"{"at":"2020-01-01T00:00:00Z"}"#).unwrap(),
+        Some(datetime(2020, 1, 1, 0, UtcOffset::UTC))
+    );
+    assert!(read(r#"{"at":"not-a-date"}"#).is_err());
+    assert_eq!(
+        serde_json::to_string(&EmptyAsNone { at: None }).unwrap(),
+        r#"{"at":null}"#
+    );
+}
+
+#[test]
+fn same_instant_ignores_the_offset_and_same_wall_clock_the_instant() {
+    let zulu = from_json("2020-01-01T00:00:00Z").unwrap();
+    let plus_two = from_json("2020-01-01T02:00:00+02:00").unwrap();
+    let wall = from_json("2020-01-01T00:00:00+02:00").unwrap();
+    assert!(time_iso8601_serde::same_instant(&zulu, &plus_two));
+    assert!(!time_iso8601_serde::same_wall_clock(&zulu, &plus_two));
+    assert!(!time_iso8601_serde::same_instant(&zulu, &wall));
+    assert!(time_iso8601_serde::same_wall_clock(&zulu, &wall));
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct NormalizeOffset {
+    #[serde(with = "time_iso8601_serde::datetime::normalize_offset")]
+    at: OffsetDateTime,
+}
+
+#[test]
+fn normalize_offset_carries_minutes_into_hours() {
+    let read = |s: &str| {
+        serde_json::from_str::<NormalizeOffset>(&format!(r#"{{"at":"{}"}}"#, s))
+            .map(|entity| entity.at)
+    };
+    let at = read("2020-01-01T00:00:00+00:90").unwrap();
+    assert_eq!(at.offset(), UtcOffset::minutes(90));
+    assert_eq!(at, datetime(2020, 1, 1, 0, UtcOffset::minutes(90)));
+    assert_eq!(
+        read("2020-01-01T00:00:00-01:75").unwrap().offset(),
+        UtcOffset::minutes(-135)
+    );
+    assert!(read("2020-01-01T00:00:00+13:90").is_err());
+    assert!(from_json("2020-01-01T00:00:00+00:90").is_err());
+}
+
+#[test]
+fn format_datetime_writes_exactly_n_digits() {
+    use time_iso8601_serde::{format_datetime, format_time_offset};
+
+    let at =
+        datetime(2020, 1, 1, 0, UtcOffset::hours(9)) + time::Duration::nanoseconds(123_456_789);
+    assert_eq!(format_datetime::<0>(at), "2020-01-01T00:00:00+09:00");
+    assert_eq!(format_datetime::<3>(at), "2020-01-01T00:00:00.123+09:00");
+    assert_eq!(format_datetime::<6>(at), "2020-01-01T00:00:00.123456+09:00");
+    assert_eq!(
+        format_datetime::<9>(at),
+        "2020-01-01T00:00:00.123456789+09:00"
+    );
+    assert_eq!(
+        format_time_offset::<6>(at.time(), at.offset()),
+        "00:00:00.123456+09:00"
+    );
+    assert_eq!(from_json(&format_datetime::<9>(at)).unwrap(), at);
+}
+
+#[test]
+fn offsets_out_of_range_are_errors_on_serialize() {
+    #[derive(Serialize)]
+    struct Optional {
+        #[serde(with = "time_iso8601_serde::datetime::optional")]
+        at: Option<OffsetDateTime>,
+    }
+
+    #[derive(Serialize)]
+    struct Times {
+        #[serde(with = "time_iso8601_serde::datetime::vec")]
+        at: Vec<OffsetDateTime>,
+    }
+
+    #[derive(Serialize)]
+    struct Zulu {
+        #[serde(with = "time_iso8601_serde::datetime::zulu")]
+        at: OffsetDateTime,
+    }
+
+    let at = datetime(2020, 1, 1, 0, UtcOffset::UTC).to_offset(UtcOffset::seconds(99 * 3_600));
+    let errors = [
+        serde_json::to_string(&Entity { at }).unwrap_err(),
+        serde_json::to_string(&Optional { at: Some(at) }).unwrap_err(),
+        serde_json::to_string(&Times { at: vec![at] }).unwrap_err(),
+        serde_json::to_string(&Zulu { at }).unwrap_err(),
+        serde_json::to_string(&time_iso8601_serde::datetime::InOffset(
+            &at,
+            UtcOffset::seconds(-99 * 3_600),
+        ))
+        .unwrap_err(),
+    ];
+    for err in errors {
+        assert!(err.to_string().contains("UTC offset in seconds"), "{}", err);
+    }
+    assert_eq!(
+        serde_json::to_string(&Optional { at: None }).unwrap(),
+        r#"{"at":null}"#
+    );
+}
+
+#[test]
+fn local_dates_out_of_range_are_errors_on_serialize() {
+    let at = Date::try_from_ymd(100_000, 12, 31)
+        .unwrap()
+        .with_time(Time::try_from_hms(23, 0, 0).unwrap())
+        .assume_utc()
+        .to_offset(UtcOffset::hours(14));
+    let err = serde_json::to_string(&Entity { at }).unwrap_err();
+    assert!(err.to_string().contains("year"), "{}", err);
+}
+
+#[test]
+fn sortable_strings_sort_chronologically() {
+    #[derive(Serialize)]
+    struct Sortable {
+        #[serde(with = "time_iso8601_serde::datetime::sortable")]
+        at: OffsetDateTime,
+    }
+
+    let shuffled = [
+        datetime(2020, 1, 1, 0, UtcOffset::hours(-10)),
+        datetime(2020, 1, 1, 0, UtcOffset::hours(2)),
+        datetime(2019, 12, 31, 23, UtcOffset::UTC),
+        datetime(2020, 1, 1, 3, UtcOffset::hours(9)),
+        datetime(2020, 1, 1, 0, UtcOffset::UTC),
+        datetime(2020, 1, 1, 0, UtcOffset::minutes(330)),
+    ];
+    let mut strings = shuffled
+        .iter()
+        .map(|&at| serde_json::to_string(&Sortable { at }).unwrap())
+        .collect::<Vec<_>>();
+    strings.sort();
+    let mut chronological = shuffled.to_vec();
+    chronological.sort();
+    assert_eq!(
+        strings,
+        chronological
+            .iter()
+            .map(|&at| serde_json::to_string(&Sortable { at }).unwrap())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(strings[0], r#"{"at":"2019-12-31T18:00:00.000000000Z"}"#);
+}
+
+#[test]
+fn unix_timestamp_nanos_round_trip_exactly() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Nanos {
+        #[serde(with = "time_iso8601_serde::datetime::unix_timestamp_nanos")]
+        at: OffsetDateTime,
+    }
+
+    let at = precise();
+    let json = serde_json::to_string(&Nanos { at }).unwrap();
+    assert_eq!(json, r#"{"at":1577914445123456789}"#);
+    let read = serde_json::from_str::<Nanos>(&json).unwrap().at;
+    assert_eq!(read, at);
+    assert_eq!(read.nanosecond(), 123_456_789);
+    assert_eq!(read.offset(), UtcOffset::UTC);
+
+    let before_epoch = serde_json::from_str::<Nanos>(r#"{"at":-1}"#).unwrap().at;
+    assert_eq!(
+        before_epoch,
+        Date::try_from_ymd(1969, 12, 31)
+            .unwrap()
+            .try_with_hms_nano(23, 59, 59, 999_999_999)
+            .unwrap()
+            .assume_utc()
+    );
+    let err = serde_json::from_str::<Nanos>(r#"{"at":100000000000000000000000000}"#)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("within the years -100000..=100000"), "{}", err);
+}
+
+#[test]
+fn lenient_separator_reads_a_space_like_a_t() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Spaced {
+        #[serde(with = "time_iso8601_serde::datetime::lenient_separator")]
+        at: OffsetDateTime,
+    }
+
+    let spaced = |s: &str| {
+        serde_json::from_str::<Spaced>(&format!(r#"{{"at":"{}"}}"#, s)).map(|entity| entity.at)
+    };
+    let expected = from_json("2020-01-01T12:00:00+00:00").unwrap();
+    assert_eq!(spaced("2020-01-01 12:00:00+00:00").unwrap(), expected);
+    assert_eq!(spaced("2020-01-01 12:00:00+00").unwrap(), expected);
+    assert_eq!(spaced("2020-01-01T12:00:00+00:00").unwrap(), expected);
+    assert_eq!(
+        serde_json::to_string(&Spaced { at: expected }).unwrap(),
+        r#"{"at":"2020-01-01T12:00:00.000+00:00"}"#
+    );
+    for s in [
+        "2020-01-01  12:00:00+00:00",
+        "2020-01-01 12:00:00 +00:00",
+        "2020-01-01T12:00:00 +00:00",
+    ] {
+        assert!(spaced(s).is_err(), "{}", s);
+    }
+    assert!(from_json("2020-01-01 12:00:00+00:00").is_err());
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Flexible {
+    #[serde(with = "time_iso8601_serde::datetime::flexible")]
+    at: OffsetDateTime,
+}
+
+#[test]
+fn flexible_reads_strings_and_unix_timestamps() {
+    let expected = datetime(2020, 1, 1, 0, UtcOffset::UTC);
+    for json in [
+        r#"{"at":1577836800}"#,
+        r#"{"at":"2020-01-01T00:00:00Z"}"#,
+        r#"{"at":"2020-01-01T09:00:00+09:00"}"#,
+    ] {
+        let at = serde_json::from_str::<Flexible>(json).unwrap().at;
+        assert_eq!(at, expected, "{}", json);
+        let written = serde_json::to_string(&Flexible { at }).unwrap();
+        assert_eq!(serde_json::from_str::<Flexible>(&written).unwrap().at, at);
+    }
+    assert_eq!(
+        serde_json::to_string(&Flexible { at: expected }).unwrap(),
+        r#"{"at":"2020-01-01T00:00:00.000+00:00"}"#
+    );
+}
+
+#[test]
+fn flexible_rejects_fractions_and_out_of_range_timestamps() {
+    for json in [
+        r#"{"at":1577836800.5}"#,
+        r#"{"at":18446744073709551615}"#,
+        r#"{"at":-9223372036854775808}"#,
+        r#"{"at":"1577836800"}"#,
+        r#"{"at":true}"#,
+    ] {
+        assert!(serde_json::from_str::<Flexible>(json).is_err(), "{}", json);
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Local {
+    #[serde(with = "time_iso8601_serde::date_time")]
+    at: (Date, Time),
+}
+
+#[test]
+fn date_time_pairs_round_trip_without_an_offset() {
+    let at = (
+        Date::try_from_ymd(2020, 1, 1).unwrap(),
+        Time::try_from_hms_milli(12, 30, 0, 250).unwrap(),
+    );
+    let json = serde_json::to_string(&Local { at }).unwrap();
+    assert_eq!(json, r#"{"at":"2020-01-01T12:30:00.250"}"#);
+    assert_eq!(serde_json::from_str::<Local>(&json).unwrap().at, at);
+    let with_offset = r#"{"at":"2020-01-01T12:30:00.250+09:00"}"#;
+    assert_eq!(serde_json::from_str::<Local>(with_offset).unwrap().at, at);
+}
+
+#[test]
+fn date_time_pairs_reject_invalid_dates_and_times() {
+    for s in [
+        "2020-02-30T12:00:00",
+        "2020-01-01T24:30:00",
+        "2020-01-01",
+        "12:00:00",
+    ] {
+        let json = format!(r#"{{"at":"{}"}}"#, s);
+        assert!(serde_json::from_str::<Local>(&json).is_err(), "{}", s);
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Nullable {
+    #[serde(with = "time_iso8601_serde::datetime::unix_timestamp::optional")]
+    seconds: Option<OffsetDateTime>,
+    #[serde(with = "time_iso8601_serde::datetime::unix_timestamp_millis::optional")]
+    millis: Option<OffsetDateTime>,
+    #[serde(with = "time_iso8601_serde::datetime::unix_timestamp_nanos::optional")]
+    nanos: Option<OffsetDateTime>,
+}
+
+#[test]
+fn optional_unix_timestamps_round_trip_with_null() {
+    let at = datetime(2020, 1, 1, 0, UtcOffset::UTC);
+    let nullable = Nullable {
+        seconds: Some(at),
+        millis: None,
+        nanos: Some(at),
+    };
+    let json = serde_json::to_string(&nullable).unwrap();
+    assert_eq!(
+        json,
+        r#"{"seconds":1577836800,"millis":null,"nanos":1577836800000000000}"#
+    );
+    assert_eq!(serde_json::from_str::<Nullable>(&json).unwrap(), nullable);
+}
+
+#[test]
+fn optional_unix_timestamps_reject_strings_and_out_of_range_values() {
+    for json in [
+        r#"{"seconds":"1577836800","millis":null,"nanos":null}"#,
+        r#"{"seconds":null,"millis":9223372036854775807,"nanos":null}"#,
+        r#"{"seconds":-9223372036854775808,"millis":null,"nanos":null}"#,
+    ] {
+        assert!(serde_json::from_str::<Nullable>(json).is_err(), "{}", json);
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Seconds {
+    #[serde(with = "time_iso8601_serde::datetime::seconds_precision")]
+    at: OffsetDateTime,
+}
+
+#[test]
+fn seconds_precision_drops_the_fraction_on_serialize() {
+    let json = serde_json::to_string(&Seconds { at: precise() }).unwrap();
+    assert_eq!(json, r#"{"at":"2020-01-02T03:04:05+05:30"}"#);
+    let read = serde_json::from_str::<Seconds>(&json).unwrap().at;
+    assert_eq!(read, precise() - time::Duration::nanoseconds(123_456_789));
+    assert_eq!(serde_json::to_string(&Seconds { at: read }).unwrap(), json);
+    let fraction = r#"{"at":"2020-01-02T03:04:05.5+05:30"}"#;
+    assert_eq!(
+        serde_json::from_str::<Seconds>(fraction)
+            .unwrap()
+            .at
+            .millisecond(),
+        500
+    );
+}
+
+#[test]
+fn seconds_precision_rejects_what_the_parent_module_rejects() {
+    for s in ["2020-01-02T03:04:05", "2020-01-02T03:04:60.5+05:30x"] {
+        let json = format!(r#"{{"at":"{}"}}"#, s);
+        assert!(serde_json::from_str::<Seconds>(&json).is_err(), "{}", s);
+    }
+    let at = precise().to_offset(UtcOffset::seconds(15 * 3_600));
+    assert!(serde_json::to_string(&Seconds { at }).is_err());
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Any {
+    #[serde(with = "time_iso8601_serde::datetime::any")]
+    at: OffsetDateTime,
+}
+
+#[test]
+fn any_reads_fractional_timestamps_to_the_nanosecond() {
+    let midnight = datetime(2020, 1, 1, 0, UtcOffset::UTC);
+    for (json, expected) in [
+        (
+            r#"{"at":1577836800.5}"#,
+            midnight + time::Duration::milliseconds(500),
+        ),
+        (r#"{"at":1577836800}"#, midnight),
+        (
+            r#"{"at":-0.25}"#,
+            datetime(1970, 1, 1, 0, UtcOffset::UTC) - time::Duration::milliseconds(250),
+        ),
+        (
+            r#"{"at":"2020-01-01T00:00:00.5Z"}"#,
+            midnight + time::Duration::milliseconds(500),
+        ),
+    ] {
+        let at = serde_json::from_str::<Any>(json).unwrap().at;
+        assert_eq!(at, expected, "{}", json);
+        let written = serde_json::to_string(&Any { at }).unwrap();
+        assert_eq!(serde_json::from_str::<Any>(&written).unwrap().at, at);
+    }
+}
+
+#[test]
+fn any_rejects_non_finite_and_out_of_range_timestamps() {
+    for json in [
+        r#"{"at":1e300}"#,
+        r#"{"at":-1e16}"#,
+        r#"{"at":null}"#,
+        r#"{"at":[1577836800]}"#,
+        r#"{"at":"soon"}"#,
+    ] {
+        assert!(serde_json::from_str::<Any>(json).is_err(), "{}", json);
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+struct Patch {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "time_iso8601_serde::datetime::double_option"
+    )]
+    at: Option<Option<OffsetDateTime>>,
+}
+
+#[test]
+fn double_option_tells_a_missing_field_from_null() {
+    let at = datetime(2020, 1, 1, 0, UtcOffset::UTC);
+    for (patch, json) in [
+        (Patch { at: None }, r#"{}"#),
+        (Patch { at: Some(None) }, r#"{"at":null}"#),
+        (
+            Patch { at: Some(Some(at)) },
+            r#"{"at":"2020-01-01T00:00:00.000+00:00"}"#,
+        ),
+    ] {
+        assert_eq!(serde_json::to_string(&patch).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Patch>(json).unwrap(), patch);
+    }
+}
+
+#[test]
+fn double_option_rejects_invalid_datetimes() {
+    for json in [r#"{"at":""}"#, r#"{"at":"2020-01-01"}"#, r#"{"at":0}"#] {
+        assert!(serde_json::from_str::<Patch>(json).is_err(), "{}", json);
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct UnixTuple {
+    #[serde(with = "time_iso8601_serde::datetime::unix_tuple")]
+    at: OffsetDateTime,
+}
+
+#[test]
+fn unix_tuples_floor_the_seconds_and_round_trip() {
+    let before_epoch =
+        datetime(1970, 1, 1, 0, UtcOffset::UTC) - time::Duration::milliseconds(1_500);
+    for (at, json) in [
+        (precise(), r#"{"at":[1577914445,123456789]}"#),
+        (before_epoch, r#"{"at":[-2,500000000]}"#),
+    ] {
+        assert_eq!(serde_json::to_string(&UnixTuple { at }).unwrap(), json);
+        let read = serde_json::from_str::<UnixTuple>(json).unwrap().at;
+        assert_eq!(read, at);
+        assert_eq!(read.offset(), UtcOffset::UTC);
+    }
+}
+
+#[test]
+fn unix_tuples_reject_bad_nanoseconds_and_lengths() {
+    for (json, expected) in [
+        (
+            r#"{"at":[0,1000000000]}"#,
+            "nanoseconds in 0..1_000_000_000",
+        ),
+        (r#"{"at":[0,-1]}"#, "u32"),
+        (r#"{"at":[0]}"#, "invalid length 1"),
+        (r#"{"at":[0,0,0]}"#, "invalid length 3"),
+        (r#"{"at":[9223372036854775807,0]}"#, "within the years"),
+    ] {
+        let err = serde_json::from_str::<UnixTuple>(json)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains(expected), "{}: {}", json, err);
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Structured {
+    #[serde(with = "time_iso8601_serde::datetime::structured")]
+    at: OffsetDateTime,
+}
+
+#[test]
+fn structured_datetimes_round_trip_in_json_and_bincode() {
+    let at = precise();
+    let json = serde_json::to_string(&Structured { at }).unwrap();
+    assert_eq!(
+        json,
+        r#"{"at":{"date":"2020-01-02","time":"03:04:05.123456789","offset":"+05:30"}}"#
+    );
+    let read = serde_json::from_str::<Structured>(&json).unwrap().at;
+    assert_eq!(read, at);
+    assert_eq!(read.offset(), UtcOffset::minutes(330));
+    let bincode = bincode::serialize(&Structured { at }).unwrap();
+    assert_eq!(bincode::deserialize::<Structured>(&bincode).unwrap().at, at);
+}
+
+#[test]
+fn structured_datetimes_reject_bad_fields() {
+    for (fields, expected) in [
+        (
+            r#"{"date":"2020-01-01","time":"00:00:00Z","offset":"Z"}"#,
+            "time without an offset",
+        ),
+        (
+            r#"{"date":"2020-01-01","time":"00:00:00"}"#,
+            "missing field `offset`",
+        ),
+        (
+            r#"{"date":"2020-01-01","date":"2020-01-02","time":"00:00:00","offset":"Z"}"#,
+            "duplicate field `date`",
+        ),
+        (
+            r#"{"date":"2020-01-01","time":"00:00:00","offset":"Z","zone":"UTC"}"#,
+            "unknown field `zone`",
+        ),
+        (
+            r#"{"date":"2020-02-30","time":"00:00:00","offset":"Z"}"#,
+            "2020-02-30",
+        ),
+    ] {
+        let json = format!(r#"{{"at":{}}}"#, fields);
+        let err = serde_json::from_str::<Structured>(&json)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains(expected), "{}: {}", fields, err);
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct AllDay {
+    #[serde(with = "time_iso8601_serde::datetime::date_if_midnight")]
+    at: OffsetDateTime,
+}
+
+#[test]
+fn date_if_midnight_writes_a_bare_date_only_for_midnight_utc() {
+    for (at, json) in [
+        (
+            datetime(2020, 1, 2, 0, UtcOffset::UTC),
+            r#"{"at":"2020-01-02"}"#,
+        ),
+        (
+            datetime(2020, 1, 2, 3, UtcOffset::UTC),
+            r#"{"at":"2020-01-02T03:00:00.000+00:00"}"#,
+        ),
+        (
+            datetime(2020, 1, 2, 0, UtcOffset::hours(1)),
+            r#"{"at":"2020-01-02T00:00:00.000+01:00"}"#,
+        ),
+    ] {
+        assert_eq!(serde_json::to_string(&AllDay { at }).unwrap(), json);
+        assert_eq!(serde_json::from_str::<AllDay>(json).unwrap().at, at);
+    }
+    let at = datetime(2020, 1, 2, 0, UtcOffset::UTC);
+    let bincode = bincode::serialize(&AllDay { at }).unwrap();
+    assert_eq!(bincode::deserialize::<AllDay>(&bincode).unwrap().at, at);
+}
+
+#[test]
+fn date_if_midnight_rejects_malformed_dates() {
+    for s in [
+        "2020-13-02",
+        "2020-01-32",
+        "2020-01-02junk",
+        "",
+        "2020-01-02T25:00:00Z",
+    ] {
+        let json = format!(r#"{{"at":"{}"}}"#, s);
+        assert!(serde_json::from_str::<AllDay>(&json).is_err(), "{}", s);
+    }
+}
@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+use time::Duration;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Entity {
+    #[serde(with = "time_iso8601_serde::duration")]
+    for_: Duration,
+}
+
+fn to_json(for_: Duration) -> String {
+    serde_json::to_string(&Entity { for_ }).unwrap()
+}
+
+fn from_json(s: &str) -> Duration {
+    serde_json::from_str::<Entity>(&format!(r#"{{"for_":"{}"}}"#, s))
+        .unwrap()
+        .for_
+}
+
+#[test]
+fn durations_round_trip_in_canonical_form() {
+    for (duration, expected) in [
+        (Duration::zero(), "PT0S"),
+        (Duration::weeks(1), "P7D"),
+        (Duration::hours(25), "P1DT1H"),
+        (Duration::seconds(90), "PT1M30S"),
+        (Duration::milliseconds(1_500), "PT1.500S"),
+        (-Duration::hours(36), "-P1DT12H"),
+    ] {
+        let json = to_json(duration);
+        assert_eq!(json, format!(r#"{{"for_":"{}"}}"#, expected));
+        assert_eq!(from_json(expected), duration);
+    }
+}
+
+#[test]
+fn equal_durations_are_written_identically() {
+    let written = ["P1W", "P7D", "PT168H", "PT10080M", "P6DT24H"]
+        .iter()
+        .map(|s| to_json(from_json(s)))
+        .collect::<Vec<_>>();
+    assert!(
+        written.iter().all(|json| json == r#"{"for_":"P7D"}"#),
+        "{:?}",
+        written
+    );
+}
+
+fn try_from_json(s: &str) -> Result<Duration, String> {
+    serde_json::from_str::<Entity>(&format!(r#"{{"for_":"{}"}}"#, s))
+        .map(|entity| entity.for_)
+        .map_err(|err| err.to_string())
+}
+
+#[test]
+fn negative_durations_are_read_with_a_leading_minus() {
+    assert_eq!(
+        from_json("-PT1H30M"),
+        -(Duration::hours(1) + Duration::minutes(30))
+    );
+    assert_eq!(from_json("-P2D"), -Duration::days(2));
+    assert_eq!(from_json("-PT0.5S"), -Duration::milliseconds(500));
+}
+
+#[test]
+fn overflowing_durations_are_errors() {
+    for s in [
+        "P1000000000000Y",
+        "P1000000000000000000D",
+        "PT1000000000000000000H",
+        "-P1000000000000000000W",
+    ] {
+        assert!(try_from_json(s).is_err(), "{}", s);
+    }
+}
+
+#[test]
+fn durations_up_to_u32_max_days_round_trip_either_way() {
+    let longest = Duration::days(i64::from(u32::MAX)) + Duration::nanoseconds(86_399_999_999_999);
+    for duration in [longest, -longest] {
+        let json = to_json(duration);
+        assert_eq!(
+            serde_json::from_str::<Entity>(&json).unwrap().for_,
+            duration
+        );
+    }
+    assert_eq!(
+        to_json(-longest),
+        r#"{"for_":"-P4294967295DT23H59M59.999999999S"}"#
+    );
+    for duration in [
+        Duration::days(i64::from(u32::MAX) + 1),
+        -Duration::days(i64::from(u32::MAX) + 1),
+        Duration::max_value(),
+        Duration::min_value(),
+    ] {
+        assert!(serde_json::to_string(&Entity { for_: duration }).is_err());
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Seconds {
+    #[serde(with = "time_iso8601_serde::duration::seconds")]
+    for_: Duration,
+}
+
+#[test]
+fn seconds_are_integers_when_whole_and_floats_otherwise() {
+    for (for_, json) in [
+        (Duration::seconds(90), r#"{"for_":90}"#),
+        (-Duration::seconds(90), r#"{"for_":-90}"#),
+        (Duration::milliseconds(1_500), r#"{"for_":1.5}"#),
+        (Duration::milliseconds(2_010), r#"{"for_":2.01}"#),
+    ] {
+        assert_eq!(serde_json::to_string(&Seconds { for_ }).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Seconds>(json).unwrap().for_, for_);
+    }
+    let for_ = Duration::milliseconds(1_500);
+    let bincode = bincode::serialize(&Seconds { for_ }).unwrap();
+    assert_eq!(
+        bincode::deserialize::<Seconds>(&bincode).unwrap().for_,
+        for_
+    );
+}
+
+#[test]
+fn seconds_reject_strings_and_numbers_out_of_range() {
+    for json in [
+        r#"{"for_":"PT90S"}"#,
+        r#"{"for_":18446744073709551615}"#,
+        r#"{"for_":1e300}"#,
+        r#"{"for_":null}"#,
+    ] {
+        assert!(serde_json::from_str::<Seconds>(json).is_err(), "{}", json);
+    }
+}
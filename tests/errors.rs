@@ -0,0 +1,133 @@
+use serde::Deserialize;
+use time::{Date, OffsetDateTime};
+
+#[derive(Debug, Deserialize)]
+struct Single {
+    #[serde(with = "time_iso8601_serde::date")]
+    _on: Date,
+}
+
+#[derive(Debug, Deserialize)]
+struct Many {
+    #[serde(with = "time_iso8601_serde::date::vec")]
+    _on: Vec<Date>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Entity {
+    #[serde(with = "time_iso8601_serde::datetime")]
+    _at: OffsetDateTime,
+}
+
+fn message(err: serde_json::Error) -> String {
+    let err = err.to_string();
+    err[..err.rfind(" at line").unwrap()].to_owned()
+}
+
+#[test]
+fn rejected_strings_are_invalid_values() {
+    let single = |s: &str| message(serde_json::from_str::<Single>(s).unwrap_err());
+    let many = |s: &str| message(serde_json::from_str::<Many>(s).unwrap_err());
+    assert_eq!(
+        single(r#"{"_on":"junk"}"#),
+        r#"invalid value: string "junk", expected an ISO 8601 date"#
+    );
+    assert_eq!(
+        single(r#"{"_on":"2021-02-29"}"#),
+        r#"invalid value: string "2021-02-29", expected a valid ISO 8601 value (February 2021 has only 28 days, got day 29)"#
+    );
+    assert_eq!(
+        many(r#"{"_on":["2020-01-01","junk"]}"#),
+        single(r#"{"_on":"junk"}"#)
+    );
+    assert_eq!(
+        message(
+            serde_json::from_str::<Entity>(r#"{"_at":"2020-01-01T00:00:00+00:90"}"#).unwrap_err()
+        ),
+        r#"invalid value: string "2020-01-01T00:00:00+00:90", expected a valid ISO 8601 value (offset minute must be in the range 0..=59, got 90)"#
+    );
+}
+
+#[test]
+fn long_inputs_are_cut_short_in_errors() {
+    let input = "x".repeat(100);
+    let err =
+        message(serde_json::from_str::<Single>(&format!(r#"{{"_on":"{}"}}"#, input)).unwrap_err());
+    assert_eq!(
+        err,
+        format!(
+            r#"invalid value: string "{}", expected an ISO 8601 date"#,
+            "x".repeat(64)
+        )
+    );
+}
+
+#[derive(Debug, Deserialize)]
+struct TimeOffset {
+    #[serde(with = "time_iso8601_serde::time_offset")]
+    _at: (time::Time, time::UtcOffset),
+}
+
+#[test]
+fn empty_and_blank_strings_get_the_same_message_in_each_module() {
+    for input in ["", "   ", "\t"] {
+        let json = |field: &str| format!(r#"{{"{}":"{}"}}"#, field, input.escape_default());
+        for (err, expected) in [
+            (
+                message(serde_json::from_str::<Entity>(&json("_at")).unwrap_err()),
+                "datetime",
+            ),
+            (
+                message(serde_json::from_str::<Single>(&json("_on")).unwrap_err()),
+                "date",
+            ),
+            (
+                message(serde_json::from_str::<TimeOffset>(&json("_at")).unwrap_err()),
+                "time",
+            ),
+        ] {
+            assert_eq!(
+                err,
+                format!(
+                    "invalid value: empty string, expected an ISO 8601 {}",
+                    expected
+                )
+            );
+        }
+    }
+}
+
+#[test]
+fn trailing_input_is_rejected() {
+    for s in [
+        "2020-01-01T00:00:00Zgarbage",
+        "2020-01-01T00:00:00+09:00x",
+        "2020-01-01T00:00:00.5 ",
+        "2020-01-01T00:00:00.+00:00",
+        "2020-W01-1T00:00:00Z!",
+    ] {
+        let json = format!(r#"{{"_at":"{}"}}"#, s);
+        let err = message(serde_json::from_str::<Entity>(&json).unwrap_err());
+        assert!(
+            err.ends_with("expected an ISO 8601 datetime"),
+            "{}: {}",
+            s,
+            err
+        );
+    }
+    for s in ["2020-01-01x", "2020-01-011", "2020-W01-1-", "2020-366 "] {
+        let json = format!(r#"{{"_on":"{}"}}"#, s);
+        let err = message(serde_json::from_str::<Single>(&json).unwrap_err());
+        assert!(err.ends_with("expected an ISO 8601 date"), "{}: {}", s, err);
+    }
+    for s in [
+        "12:00:00garbage",
+        "12:00:00Zx",
+        "12:00:00+09:00:00:00",
+        "12:00:",
+    ] {
+        let json = format!(r#"{{"_at":"{}"}}"#, s);
+        let err = message(serde_json::from_str::<TimeOffset>(&json).unwrap_err());
+        assert!(err.ends_with("expected an ISO 8601 time"), "{}: {}", s, err);
+    }
+}
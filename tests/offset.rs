@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+use time::{OffsetDateTime, UtcOffset};
+use time_iso8601_serde::has_unknown_local_offset;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Offset {
+    #[serde(with = "time_iso8601_serde::utc_offset")]
+    offset: UtcOffset,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Entity {
+    #[serde(with = "time_iso8601_serde::datetime")]
+    at: OffsetDateTime,
+}
+
+fn offset_from_json(s: &str) -> Result<UtcOffset, serde_json::Error> {
+    serde_json::from_str::<Offset>(&format!(r#"{{"offset":"{}"}}"#, s)).map(|o| o.offset)
+}
+
+#[test]
+fn offset_seconds_beyond_fourteen_hours_are_rejected() {
+    for s in ["+14:00:30", "-14:00:01", "+140001"] {
+        assert!(offset_from_json(s).is_err(), "{}", s);
+        let datetime = format!(r#"{{"at":"2020-01-01T00:00:00{}"}}"#, s);
+        assert!(serde_json::from_str::<Entity>(&datetime).is_err(), "{}", s);
+    }
+}
+
+#[test]
+fn offsets_at_the_bounds_round_trip() {
+    for (s, seconds) in [
+        ("+14:00", 50_400),
+        ("-14:00", -50_400),
+        ("+13:59:59", 50_399),
+    ] {
+        let offset = offset_from_json(s).unwrap();
+        assert_eq!(offset.as_seconds(), seconds);
+        let json = serde_json::to_string(&Offset { offset }).unwrap();
+        assert_eq!(json, format!(r#"{{"offset":"{}"}}"#, s));
+    }
+}
+
+#[test]
+fn negative_and_partial_hour_offsets_are_written_with_one_sign() {
+    for (minutes, s) in [(-330, "-05:30"), (-30, "-00:30"), (345, "+05:45")] {
+        let offset = UtcOffset::minutes(minutes);
+        assert_eq!(
+            serde_json::to_string(&Offset { offset }).unwrap(),
+            format!(r#"{{"offset":"{}"}}"#, s)
+        );
+        let at = OffsetDateTime::unix_epoch().to_offset(offset);
+        let json = serde_json::to_string(&Entity { at }).unwrap();
+        assert!(json.ends_with(&format!(r#"{}"}}"#, s)), "{}", json);
+        assert_eq!(
+            serde_json::from_str::<Entity>(&json).unwrap().at.offset(),
+            offset
+        );
+    }
+}
+
+#[test]
+fn basic_and_hour_only_offsets_match_the_extended_form() {
+    for s in ["+0100", "+01", "+01:00"] {
+        assert_eq!(offset_from_json(s).unwrap(), UtcOffset::hours(1), "{}", s);
+        let datetime = format!(r#"{{"at":"2020-01-01T00:00:00{}"}}"#, s);
+        let at = serde_json::from_str::<Entity>(&datetime).unwrap().at;
+        assert_eq!(at.offset(), UtcOffset::hours(1), "{}", s);
+    }
+}
+
+#[test]
+fn hour_only_offsets_are_written_with_minutes() {
+    for (s, expected) in [("+05", "+05:00"), ("-08", "-08:00")] {
+        let datetime = format!(r#"{{"at":"2020-01-01T00:00:00{}"}}"#, s);
+        let at = serde_json::from_str::<Entity>(&datetime).unwrap().at;
+        assert_eq!(
+            serde_json::to_string(&Entity { at }).unwrap(),
+            format!(r#"{{"at":"2020-01-01T00:00:00.000{}"}}"#, expected)
+        );
+        let offset = offset_from_json(s).unwrap();
+        assert_eq!(
+            serde_json::to_string(&Offset { offset }).unwrap(),
+            format!(r#"{{"offset":"{}"}}"#, expected)
+        );
+    }
+}
+
+#[test]
+fn negative_zero_is_read_as_utc_but_flagged_as_unknown() {
+    let unknown = "2020-01-01T00:00:00-00:00";
+    let utc = "2020-01-01T00:00:00+00:00";
+    let datetimes = [unknown, utc]
+        .iter()
+        .map(|s| {
+            serde_json::from_str::<Entity>(&format!(r#"{{"at":"{}"}}"#, s))
+                .unwrap()
+                .at
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(datetimes[0], datetimes[1]);
+    assert_eq!(datetimes[0].offset(), UtcOffset::UTC);
+    assert!(has_unknown_local_offset(unknown));
+    assert!(has_unknown_local_offset("2020-01-01T00:00:00-0000"));
+    assert!(!has_unknown_local_offset(utc));
+    assert!(!has_unknown_local_offset("2020-01-01T00:00:00Z"));
+    assert!(!has_unknown_local_offset("2020-01-01T00:00:00-01:00"));
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Minutes {
+    #[serde(with = "time_iso8601_serde::utc_offset::minutes")]
+    offset: UtcOffset,
+}
+
+#[test]
+fn offsets_in_minutes_round_trip() {
+    for (offset, json) in [
+        (UtcOffset::UTC, r#"{"offset":0}"#),
+        (UtcOffset::minutes(330), r#"{"offset":330}"#),
+        (UtcOffset::minutes(-570), r#"{"offset":-570}"#),
+        (UtcOffset::hours(14), r#"{"offset":840}"#),
+    ] {
+        assert_eq!(serde_json::to_string(&Minutes { offset }).unwrap(), json);
+        assert_eq!(
+            serde_json::from_str::<Minutes>(json).unwrap().offset,
+            offset
+        );
+    }
+    let offset = UtcOffset::minutes(-570);
+    let bincode = bincode::serialize(&Minutes { offset }).unwrap();
+    assert_eq!(
+        bincode::deserialize::<Minutes>(&bincode).unwrap().offset,
+        offset
+    );
+}
+
+#[test]
+fn offsets_in_minutes_reject_values_past_14_hours_and_strings() {
+    for json in [
+        r#"{"offset":841}"#,
+        r#"{"offset":-841}"#,
+        r#"{"offset":"+05:30"}"#,
+        r#"{"offset":1.5}"#,
+    ] {
+        assert!(serde_json::from_str::<Minutes>(json).is_err(), "{}", json);
+    }
+    for offset in [UtcOffset::minutes(15 * 60), UtcOffset::seconds(30)] {
+        assert!(serde_json::to_string(&Minutes { offset }).is_err());
+    }
+}
@@ -0,0 +1,37 @@
+#![cfg(feature = "schemars")]
+
+use schemars::schema::{InstanceType, Schema, SingleOrVec};
+use schemars::{schema_for, JsonSchema};
+use time::{Date, OffsetDateTime, Time, UtcOffset};
+use time_iso8601_serde::Iso8601;
+
+fn string_format<T: JsonSchema>() -> String {
+    let schema = schema_for!(T).schema;
+    assert_eq!(
+        schema.instance_type,
+        Some(SingleOrVec::Single(Box::new(InstanceType::String)))
+    );
+    schema.format.unwrap()
+}
+
+#[test]
+fn wrapper_schemas_describe_what_they_serialize() {
+    assert_eq!(string_format::<Iso8601<OffsetDateTime>>(), "date-time");
+    assert_eq!(string_format::<Iso8601<Date>>(), "date");
+    assert_eq!(string_format::<Iso8601<(Time, UtcOffset)>>(), "time");
+
+    let on = Iso8601(Date::try_from_ymd(2020, 1, 1).unwrap());
+    let json = serde_json::to_value(on).unwrap();
+    assert_eq!(json, "2020-01-01");
+    assert_eq!(serde_json::from_value::<Iso8601<Date>>(json).unwrap(), on);
+}
+
+#[test]
+fn wrapper_schemas_are_inlined_and_reject_other_formats() {
+    let mut gen = schemars::gen::SchemaGenerator::default();
+    let schema = gen.subschema_for::<Iso8601<Date>>();
+    assert!(matches!(schema, Schema::Object(ref object) if object.reference.is_none()));
+    assert!(gen.definitions().is_empty());
+    assert!(serde_json::from_str::<Iso8601<Date>>(r#""2020-01-01T00:00:00Z""#).is_err());
+    assert!(serde_json::from_str::<Iso8601<OffsetDateTime>>(r#""2020-01-01""#).is_err());
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "serde_with")]
+
+use serde::{Deserialize, Serialize};
+use serde_with::As;
+use time::{Date, OffsetDateTime, UtcOffset};
+use time_iso8601_serde::serde_with::{
+    Iso8601, Iso8601Config, Iso8601Custom, Iso8601Week, ZuluMillis,
+};
+
+fn datetime(s: &str) -> OffsetDateTime {
+    time_iso8601_serde::parse_datetime(s).unwrap()
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Entity {
+    #[serde(with = "As::<Iso8601>")]
+    at: OffsetDateTime,
+    #[serde(with = "As::<Option<Vec<Iso8601>>>")]
+    on: Option<Vec<Date>>,
+    #[serde(with = "As::<Vec<Option<Iso8601Week>>>")]
+    weeks: Vec<Option<Date>>,
+}
+
+#[test]
+fn markers_round_trip_inside_containers() {
+    let on = Date::try_from_ymd(2020, 1, 1).unwrap();
+    let entity = Entity {
+        at: datetime("2020-01-01T12:00:00+09:00"),
+        on: Some(vec![on]),
+        weeks: vec![Some(on), None],
+    };
+    let json = serde_json::to_string(&entity).unwrap();
+    assert_eq!(
+        json,
+        r#"{"at":"2020-01-01T12:00:00.000+09:00","on":["2020-01-01"],"weeks":["2020-W01-3",null]}"#
+    );
+    let read = serde_json::from_str::<Entity>(&json).unwrap();
+    assert_eq!(read, entity);
+    assert_eq!(read.at.offset(), UtcOffset::hours(9));
+}
+
+#[test]
+fn markers_reject_what_their_modules_reject() {
+    for json in [
+        r#"{"at":"2020-01-01T12:00:00","on":null,"weeks":[]}"#,
+        r#"{"at":"2020-01-01T12:00:00Z","on":["2020-02-30"],"weeks":[]}"#,
+        r#"{"at":"2020-01-01T12:00:00Z","on":null,"weeks":["2020-W54-1"]}"#,
+    ] {
+        assert!(serde_json::from_str::<Entity>(json).is_err(), "{}", json);
+    }
+}
+
+struct UtcSeconds;
+
+impl Iso8601Config for UtcSeconds {
+    const FRACTION_DIGITS: Option<u32> = Some(0);
+    const ASSUME_UTC: bool = true;
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Custom {
+    #[serde(with = "As::<Iso8601Custom<ZuluMillis>>")]
+    zulu: OffsetDateTime,
+    #[serde(with = "As::<Iso8601Custom<UtcSeconds>>")]
+    seconds: OffsetDateTime,
+}
+
+#[test]
+fn custom_markers_write_as_configured() {
+    let at = datetime("2020-01-01T00:00:00.123456Z");
+    let json = serde_json::to_string(&Custom {
+        zulu: at,
+        seconds: at,
+    })
+    .unwrap();
+    assert_eq!(
+        json,
+        r#"{"zulu":"2020-01-01T00:00:00.123Z","seconds":"2020-01-01T00:00:00+00:00"}"#
+    );
+    let read = serde_json::from_str::<Custom>(&json).unwrap();
+    assert_eq!(read.zulu, datetime("2020-01-01T00:00:00.123Z"));
+    assert_eq!(read.seconds, datetime("2020-01-01T00:00:00Z"));
+    let local = r#"{"zulu":"2020-01-01T00:00:00Z","seconds":"2020-01-01T09:00:00"}"#;
+    assert_eq!(
+        serde_json::from_str::<Custom>(local).unwrap().seconds,
+        datetime("2020-01-01T09:00:00Z")
+    );
+}
+
+#[test]
+fn custom_markers_reject_missing_offsets_unless_assuming_utc() {
+    let local = r#"{"zulu":"2020-01-01T00:00:00","seconds":"2020-01-01T00:00:00Z"}"#;
+    let err = serde_json::from_str::<Custom>(local)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("datetime has no UTC offset"), "{}", err);
+    let out_of_range = Custom {
+        zulu: datetime("2020-01-01T00:00:00Z").to_offset(UtcOffset::seconds(99 * 3_600)),
+        seconds: datetime("2020-01-01T00:00:00Z"),
+    };
+    assert!(serde_json::to_string(&out_of_range).is_err());
+}
@@ -0,0 +1,187 @@
+use serde::{Deserialize, Serialize};
+use time::{Time, UtcOffset};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Entity {
+    #[serde(with = "time_iso8601_serde::time_offset")]
+    at: (Time, UtcOffset),
+}
+
+fn from_json(s: &str) -> Result<(Time, UtcOffset), String> {
+    serde_json::from_str::<Entity>(&format!(r#"{{"at":"{}"}}"#, s))
+        .map(|entity| entity.at)
+        .map_err(|err| err.to_string())
+}
+
+#[test]
+fn datetime_is_not_a_time() {
+    for s in ["2020-01-01T12:00:00Z", "2020-01-01T12:00:00+09:00"] {
+        let err = from_json(s).unwrap_err();
+        assert!(err.contains("expected an ISO 8601 time"), "{}: {}", s, err);
+    }
+}
+
+#[test]
+fn conflicting_zone_designators_are_rejected() {
+    let err = from_json("12:00:00Z+02:00").unwrap_err();
+    assert!(err.contains("both a Z and a numeric UTC offset"), "{}", err);
+}
+
+#[test]
+fn nanoseconds_round_trip() {
+    let at = (
+        Time::try_from_hms_nano(12, 0, 0, 123_456_789).unwrap(),
+        UtcOffset::UTC,
+    );
+    let json = serde_json::to_string(&Entity { at }).unwrap();
+    assert_eq!(json, r#"{"at":"12:00:00.123456789+00:00"}"#);
+    assert_eq!(serde_json::from_str::<Entity>(&json).unwrap().at, at);
+}
+
+#[test]
+fn end_of_day_needs_a_date() {
+    let err = from_json("24:00:00Z").unwrap_err();
+    assert!(err.contains("24:00:00 ends a day"), "{}", err);
+}
+
+#[test]
+fn offsets_beyond_fourteen_hours_are_rejected() {
+    for s in ["12:00:00+20:00", "12:00:00-14:01", "12:00:00+1430"] {
+        assert!(from_json(s).is_err(), "{}", s);
+    }
+    assert_eq!(from_json("12:00:00+14:00").unwrap().1, UtcOffset::hours(14));
+    let at = (Time::midnight(), UtcOffset::hours(20));
+    assert!(serde_json::to_string(&Entity { at }).is_err());
+}
+
+#[test]
+fn comma_fractions_are_read_and_written_with_a_dot() {
+    let at = from_json("12:00:00,500+00:00").unwrap();
+    assert_eq!(
+        at,
+        (
+            Time::try_from_hms_milli(12, 0, 0, 500).unwrap(),
+            UtcOffset::UTC
+        )
+    );
+    assert_eq!(
+        serde_json::to_string(&Entity { at }).unwrap(),
+        r#"{"at":"12:00:00.500+00:00"}"#
+    );
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct TimeOrDatetime {
+    #[serde(with = "time_iso8601_serde::time_offset::from_datetime_or_time")]
+    at: (Time, UtcOffset),
+}
+
+#[test]
+fn from_datetime_or_time_keeps_the_time_and_offset_of_either_input() {
+    let at = |s: &str| {
+        serde_json::from_str::<TimeOrDatetime>(&format!(r#"{{"at":"{}"}}"#, s))
+            .map(|entity| entity.at)
+    };
+    let expected = (Time::try_from_hms(12, 0, 0).unwrap(), UtcOffset::hours(9));
+    assert_eq!(at("12:00:00+09:00").unwrap(), expected);
+    assert_eq!(at("2020-01-01T12:00:00+09:00").unwrap(), expected);
+    assert_eq!(
+        serde_json::to_string(&TimeOrDatetime { at: expected }).unwrap(),
+        r#"{"at":"12:00:00.000+09:00"}"#
+    );
+    assert!(at("2020-02-30T12:00:00+09:00").is_err());
+    assert!(at("2020-01-01T12:00:00").is_err());
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Reduced {
+    #[serde(with = "time_iso8601_serde::time_offset::reduced")]
+    at: (Time, UtcOffset),
+    #[serde(with = "time_iso8601_serde::time_of_day::reduced")]
+    local: Time,
+}
+
+#[test]
+fn reduced_times_fill_in_zero_minutes_and_seconds() {
+    for (at, local, expected_at, expected_local) in [
+        (
+            "12:30+09:00",
+            "12:30",
+            (12, 30, UtcOffset::hours(9)),
+            (12, 30),
+        ),
+        ("12Z", "09", (12, 0, UtcOffset::UTC), (9, 0)),
+        ("12:30:15Z", "12:30:15", (12, 30, UtcOffset::UTC), (12, 30)),
+    ] {
+        let json = format!(r#"{{"at":"{}","local":"{}"}}"#, at, local);
+        let reduced = serde_json::from_str::<Reduced>(&json).unwrap();
+        let (hour, minute, offset) = expected_at;
+        assert_eq!(reduced.at.0.hour(), hour, "{}", at);
+        assert_eq!(reduced.at.0.minute(), minute, "{}", at);
+        assert_eq!(reduced.at.1, offset, "{}", at);
+        assert_eq!(
+            (reduced.local.hour(), reduced.local.minute()),
+            expected_local,
+            "{}",
+            local
+        );
+        let written = serde_json::to_string(&reduced).unwrap();
+        assert_eq!(serde_json::from_str::<Reduced>(&written).unwrap(), reduced);
+    }
+    let reduced = serde_json::from_str::<Reduced>(r#"{"at":"12:30Z","local":"12"}"#).unwrap();
+    assert_eq!(
+        serde_json::to_string(&reduced).unwrap(),
+        r#"{"at":"12:30:00.000+00:00","local":"12:00:00.000"}"#
+    );
+}
+
+#[test]
+fn reduced_times_reject_out_of_range_components() {
+    for (at, local) in [
+        ("24Z", "12"),
+        ("12:60Z", "12"),
+        ("12Z", "1"),
+        ("12Z", "12:3"),
+        ("12+15:00", "12"),
+    ] {
+        let json = format!(r#"{{"at":"{}","local":"{}"}}"#, at, local);
+        assert!(serde_json::from_str::<Reduced>(&json).is_err(), "{}", json);
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Shifts {
+    #[serde(with = "time_iso8601_serde::time_offset::vec")]
+    starts: Vec<(Time, UtcOffset)>,
+}
+
+#[test]
+fn time_offset_vectors_round_trip() {
+    let shifts = Shifts {
+        starts: vec![
+            (Time::try_from_hms(9, 0, 0).unwrap(), UtcOffset::hours(9)),
+            (
+                Time::try_from_hms(17, 30, 0).unwrap(),
+                UtcOffset::minutes(-150),
+            ),
+        ],
+    };
+    let json = serde_json::to_string(&shifts).unwrap();
+    assert_eq!(
+        json,
+        r#"{"starts":["09:00:00.000+09:00","17:30:00.000-02:30"]}"#
+    );
+    assert_eq!(serde_json::from_str::<Shifts>(&json).unwrap(), shifts);
+}
+
+#[test]
+fn time_offset_vectors_reject_bad_elements_and_offsets() {
+    let err = serde_json::from_str::<Shifts>(r#"{"starts":["09:00:00Z","25:00:00Z"]}"#)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("25:00:00Z"), "{}", err);
+    let out_of_range = Shifts {
+        starts: vec![(Time::midnight(), UtcOffset::hours(15))],
+    };
+    assert!(serde_json::to_string(&out_of_range).is_err());
+}
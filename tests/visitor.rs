@@ -0,0 +1,121 @@
+use serde::de::value::{BytesDeserializer, Error, SeqDeserializer, StringDeserializer};
+use serde::de::IntoDeserializer;
+use serde::Deserialize;
+use time::{Date, Duration, OffsetDateTime};
+
+#[derive(Debug, Deserialize)]
+struct Entity {
+    #[serde(with = "time_iso8601_serde::datetime")]
+    datetime: OffsetDateTime,
+    #[serde(with = "time_iso8601_serde::date::vec")]
+    dates: Vec<Date>,
+    #[serde(with = "time_iso8601_serde::duration")]
+    duration: Duration,
+    #[serde(with = "time_iso8601_serde::duration::optional")]
+    optional_duration: Option<Duration>,
+    #[serde(with = "time_iso8601_serde::interval")]
+    interval: (OffsetDateTime, OffsetDateTime),
+}
+
+const JSON: &str = r#"{
+    "datetime": "2020-01-01T00:00:00Z",
+    "dates": ["2020-01-01", "2020-01-02"],
+    "duration": "PT1H",
+    "optional_duration": "P1D",
+    "interval": "2020-01-01T00:00:00Z/PT1H"
+}"#;
+
+fn check(entity: Entity) {
+    assert_eq!(entity.datetime.unix_timestamp(), 1_577_836_800);
+    assert_eq!(entity.dates.len(), 2);
+    assert_eq!(entity.duration, Duration::hours(1));
+    assert_eq!(entity.optional_duration, Some(Duration::days(1)));
+    assert_eq!(entity.interval.1 - entity.interval.0, Duration::hours(1));
+}
+
+#[test]
+fn borrowed_and_owned_strings_parse_alike() {
+    check(serde_json::from_str(JSON).unwrap());
+    check(serde_json::from_reader(JSON.as_bytes()).unwrap());
+    check(serde_json::from_value(serde_json::from_str(JSON).unwrap()).unwrap());
+}
+
+#[test]
+fn byte_strings_parse_like_strings() {
+    let datetime = time_iso8601_serde::datetime::deserialize(BytesDeserializer::<Error>::new(
+        b"2020-01-01T00:00:00Z",
+    ))
+    .unwrap();
+    assert_eq!(datetime.unix_timestamp(), 1_577_836_800);
+    let duration =
+        time_iso8601_serde::duration::deserialize(BytesDeserializer::<Error>::new(b"PT1S"))
+            .unwrap();
+    assert_eq!(duration, Duration::second());
+    let interval = time_iso8601_serde::interval::deserialize(BytesDeserializer::<Error>::new(
+        b"2020-01-01T00:00:00Z/2020-01-02T00:00:00Z",
+    ))
+    .unwrap();
+    assert_eq!(interval.1 - interval.0, Duration::day());
+    let dates: Vec<&[u8]> = vec![b"2020-01-01"];
+    let dates = time_iso8601_serde::date::vec::deserialize(SeqDeserializer::<_, Error>::new(
+        dates.into_iter().map(BytesDeserializer::<Error>::new),
+    ))
+    .unwrap();
+    assert_eq!(dates, [Date::try_from_ymd(2020, 1, 1).unwrap()]);
+    assert!(
+        time_iso8601_serde::date::deserialize(BytesDeserializer::<Error>::new(b"\xff")).is_err()
+    );
+}
+
+#[test]
+fn empty_strings_get_the_same_message_everywhere() {
+    let empty = || -> StringDeserializer<Error> { String::new().into_deserializer() };
+    let errors = [
+        time_iso8601_serde::duration::deserialize(empty()).unwrap_err(),
+        time_iso8601_serde::interval::deserialize(empty()).unwrap_err(),
+        time_iso8601_serde::date::vec::deserialize(SeqDeserializer::<_, Error>::new(
+            vec![String::new()].into_iter(),
+        ))
+        .unwrap_err(),
+    ];
+    for (err, expected) in errors.iter().zip(["duration", "interval", "date"]) {
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "invalid value: empty string, expected an ISO 8601 {}",
+                expected
+            )
+        );
+    }
+}
+
+#[test]
+fn escaped_strings_parse_like_plain_ones() {
+    #[derive(Debug, Deserialize)]
+    struct Escaped {
+        #[serde(with = "time_iso8601_serde::datetime")]
+        datetime: OffsetDateTime,
+        #[serde(with = "time_iso8601_serde::date")]
+        date: Date,
+        #[serde(with = "time_iso8601_serde::time_offset")]
+        time: (time::Time, time::UtcOffset),
+    }
+
+    let escaped: Escaped = serde_json::from_str(
+        r#"{
+            "datetime": "2020-01-01T00:00:00\u002b00:00",
+            "date": "2020\u002d01-01",
+            "time": "12\u003a00:00+02:00"
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(escaped.datetime.unix_timestamp(), 1_577_836_800);
+    assert_eq!(escaped.date, Date::try_from_ymd(2020, 1, 1).unwrap());
+    assert_eq!(
+        escaped.time,
+        (
+            time::Time::try_from_hms(12, 0, 0).unwrap(),
+            time::UtcOffset::hours(2)
+        )
+    );
+}
@@ -0,0 +1,136 @@
+use core::convert::TryFrom;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use time::{Date, OffsetDateTime};
+use time_iso8601_serde::{Iso8601, Iso8601Date, Iso8601DateTime, Iso8601Time};
+
+fn datetime(s: &str) -> Iso8601DateTime {
+    s.parse().unwrap()
+}
+
+#[test]
+fn wrapped_values_order_and_hash_like_the_inner_ones() {
+    let values = [
+        "2020-01-01T02:00:00Z",
+        "2020-01-01T00:00:00Z",
+        "2020-01-01T02:00:00+03:00",
+        "2019-12-31T23:00:00-02:00",
+        "2020-01-01T03:00:00+01:00",
+    ]
+    .iter()
+    .map(|s| datetime(s))
+    .collect::<Vec<_>>();
+    let sorted = values.iter().copied().collect::<BTreeSet<_>>();
+    let mut inner = values
+        .iter()
+        .map(|at| at.0)
+        .collect::<Vec<OffsetDateTime>>();
+    inner.sort();
+    inner.dedup();
+    assert_eq!(sorted.iter().map(|at| at.0).collect::<Vec<_>>(), inner);
+    assert_eq!(sorted.len(), 4);
+    assert_eq!(
+        sorted.iter().next().unwrap().0.unix_timestamp(),
+        1_577_833_200
+    );
+    let hashed = values.iter().copied().collect::<HashSet<_>>();
+    assert_eq!(hashed.len(), 4);
+    assert!(hashed.contains(&datetime("2020-01-01T01:00:00Z")));
+
+    let dates = ["2020-01-02", "2019-12-31", "2020-01-01"]
+        .iter()
+        .map(|s| s.parse::<Iso8601Date>().unwrap())
+        .collect::<BTreeSet<_>>();
+    assert_eq!(
+        dates
+            .into_iter()
+            .map(Iso8601::into_inner)
+            .collect::<Vec<_>>(),
+        [
+            Date::try_from_ymd(2019, 12, 31).unwrap(),
+            Date::try_from_ymd(2020, 1, 1).unwrap(),
+            Date::try_from_ymd(2020, 1, 2).unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn display_and_from_str_round_trip() {
+    for s in [
+        "2020-01-02T03:04:05Z",
+        "2020-01-02T03:04:05.123456+09:00",
+        "20200102T030405-0530",
+        "2020-W01-1T00:00:00Z",
+        "+10000-01-01T00:00:00.000-00:53:28",
+    ] {
+        let written = s.parse::<Iso8601DateTime>().unwrap().to_string();
+        assert_eq!(
+            written.parse::<Iso8601DateTime>().unwrap().to_string(),
+            written
+        );
+        assert_eq!(written.parse::<Iso8601DateTime>().unwrap(), datetime(s));
+        assert_eq!(Iso8601DateTime::try_from(s).unwrap(), datetime(s));
+    }
+    for s in ["2020-01-01", "2020-W01-1", "2020-366"] {
+        let written = s.parse::<Iso8601Date>().unwrap().to_string();
+        assert_eq!(written.parse::<Iso8601Date>().unwrap().to_string(), written);
+    }
+    for s in ["12:00:00.5+05:30", "23:59:59Z"] {
+        let written = s.parse::<Iso8601Time>().unwrap().to_string();
+        assert_eq!(written.parse::<Iso8601Time>().unwrap().to_string(), written);
+    }
+    assert_eq!(
+        "x".parse::<Iso8601DateTime>().unwrap_err(),
+        time_iso8601_serde::Iso8601SerdeError::Parse("datetime")
+    );
+}
+
+#[test]
+fn dates_round_trip_as_json_map_keys() {
+    let counts = [("2020-01-02", 3), ("2019-12-31", 1), ("2020-01-01", 2)]
+        .iter()
+        .map(|&(s, count)| (s.parse::<Iso8601Date>().unwrap(), count))
+        .collect::<BTreeMap<_, u32>>();
+    let json = serde_json::to_string(&counts).unwrap();
+    assert_eq!(json, r#"{"2019-12-31":1,"2020-01-01":2,"2020-01-02":3}"#);
+    assert_eq!(
+        serde_json::from_str::<BTreeMap<Iso8601Date, u32>>(&json).unwrap(),
+        counts
+    );
+    assert!(serde_json::from_str::<BTreeMap<Iso8601Date, u32>>(r#"{"2020-13-01":1}"#).is_err());
+}
+
+#[test]
+fn raw_datetimes_write_back_the_string_they_were_read_from() {
+    let json = r#""20200102T030405,5+0530""#;
+    let raw: time_iso8601_serde::RawDatetime = serde_json::from_str(json).unwrap();
+    assert_eq!(raw.raw(), Some("20200102T030405,5+0530"));
+    assert_eq!(
+        raw.datetime(),
+        OffsetDateTime::from(datetime("2020-01-02T03:04:05.5+05:30"))
+    );
+    assert_eq!(serde_json::to_string(&raw).unwrap(), json);
+
+    let built = time_iso8601_serde::RawDatetime::new(raw.datetime());
+    assert_eq!(built.raw(), None);
+    let written = serde_json::to_string(&built).unwrap();
+    let read: time_iso8601_serde::RawDatetime = serde_json::from_str(&written).unwrap();
+    assert_eq!(read.datetime(), built.datetime());
+}
+
+#[test]
+fn raw_datetimes_reject_what_datetime_rejects() {
+    for json in [
+        r#""2020-01-02""#,
+        r#""2020-01-02T03:04:05""#,
+        r#""2020-13-02T03:04:05Z""#,
+        r#""2020-01-02T03:04:05Zjunk""#,
+        "1",
+    ] {
+        assert!(
+            serde_json::from_str::<time_iso8601_serde::RawDatetime>(json).is_err(),
+            "{}",
+            json
+        );
+    }
+}